@@ -0,0 +1,633 @@
+//! Helpers to convert between slices of scalar ("AoS") values and their wide ("SoA") counterparts.
+//!
+//! Batch processing with the wide types usually looks like this: chunk a `&[Vec3]` into
+//! `Wec3`s, do some math on those, then write the results back into a `&mut [Vec3]`. The functions in
+//! this module take care of that chunking, including the tail chunk when the length of the slice
+//! is not a multiple of the lane count:
+//!
+//! ```rust
+//! use ultraviolet::{batch, Vec3, Wec3};
+//!
+//! let points = [Vec3::new(1.0, 2.0, 3.0); 6];
+//! let (mut wides, tail_mask) = batch::pack::<Wec3>(&points, Vec3::zero());
+//! assert_eq!(wides.len(), 2);
+//! assert_eq!(tail_mask.move_mask(), 0b0011);
+//!
+//! for w in wides.iter_mut() {
+//!     *w *= ultraviolet::f32x4::from(2.0);
+//! }
+//!
+//! let mut out = [Vec3::zero(); 6];
+//! batch::unpack(&wides, &mut out);
+//! assert_eq!(out[5], Vec3::new(2.0, 4.0, 6.0));
+//! ```
+//!
+//! The [`Lanes`] trait also provides lane access (`extract`/`replace`), lane-wise comparisons and
+//! masked selection (`merge`) for every wide type, so that branchy algorithms can be written without
+//! dropping back to scalar code:
+//!
+//! ```rust
+//! use ultraviolet::{Lanes, Vec3, Wec3};
+//!
+//! let a = Wec3::from([Vec3::new(1.0, 2.0, 3.0); 4]);
+//! let mut b = a;
+//! b.replace(2, Vec3::new(0.0, 2.0, 3.0));
+//!
+//! // lanes where every component of `a` equals `b`
+//! let equal = a.cmp_eq(b).all();
+//! assert_eq!(equal.move_mask(), 0b1011);
+//!
+//! let picked = Wec3::merge(equal, Wec3::zero(), a);
+//! assert_eq!(picked.extract(2), Vec3::new(1.0, 2.0, 3.0));
+//! assert_eq!(picked.extract(0), Vec3::zero());
+//! ```
+use crate::geometry::*;
+use crate::*;
+
+use wide::{f32x4, f32x8, f64x4};
+
+/// A wide type made up of `LANES` scalar values of type `Element`.
+///
+/// This is implemented for the raw lane types (`f32x4`, `f32x8`, `f64x4`) as well as every wide
+/// vector, bivector, rotor, matrix, isometry and similarity type.
+///
+/// Masks follow the convention of `wide`: a lane is "set" when all of its bits are 1, and "cleared"
+/// when all of its bits are 0.
+pub trait Lanes: Copy {
+    /// The scalar type stored in each lane, e.g. `Vec3` for `Wec3`.
+    type Element: Copy;
+    /// The raw lane type used for lane masks, e.g. `f32x4` for `Wec3`.
+    type Mask: Copy;
+    /// The number of lanes.
+    const LANES: usize;
+
+    /// Builds a wide value from exactly `LANES` items, using `get` to read the element out of each item.
+    fn gather<T>(items: &[T], get: impl Fn(&T) -> Self::Element) -> Self;
+
+    /// Writes each lane into exactly `LANES` items, using `get` to find the element inside each item.
+    fn scatter<T>(self, items: &mut [T], get: impl Fn(&mut T) -> &mut Self::Element);
+
+    /// Returns a mask with the first `count` lanes set and the remaining lanes cleared.
+    fn first_lanes_mask(count: usize) -> Self::Mask;
+
+    /// Returns the value stored in `lane`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane >= LANES`.
+    fn extract(self, lane: usize) -> Self::Element;
+
+    /// Overwrites the value stored in `lane` with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane >= LANES`.
+    fn replace(&mut self, lane: usize, value: Self::Element);
+
+    /// Merge two values together lane-wise using `mask`, where a lane of `mask` is set, the output
+    /// will take the lane from `tru`, otherwise the output will take the lane from `fals`.
+    ///
+    /// Each lane of `mask` must be either all ones or all zeros, such as the masks returned by
+    /// [`first_lanes_mask`](Lanes::first_lanes_mask) or the `cmp_*` methods.
+    fn merge(mask: Self::Mask, tru: Self, fals: Self) -> Self;
+
+    /// Component-wise `self < rhs`, returning a value whose components are masks.
+    ///
+    /// Use [`all`](Lanes::all) or [`any`](Lanes::any) to reduce the result to a lane mask.
+    fn cmp_lt(self, rhs: Self) -> Self;
+
+    /// Component-wise `self == rhs`, returning a value whose components are masks.
+    ///
+    /// Use [`all`](Lanes::all) or [`any`](Lanes::any) to reduce the result to a lane mask.
+    fn cmp_eq(self, rhs: Self) -> Self;
+
+    /// Reduces a component-wise mask (as returned by `cmp_lt`/`cmp_eq`) to a lane mask which is set
+    /// in the lanes where every component is set.
+    ///
+    /// For the raw lane types this is the identity; note that their inherent `all` (which returns
+    /// a `bool`) takes precedence in method call syntax.
+    fn all(self) -> Self::Mask;
+
+    /// Reduces a component-wise mask (as returned by `cmp_lt`/`cmp_eq`) to a lane mask which is set
+    /// in the lanes where any component is set.
+    ///
+    /// For the raw lane types this is the identity; note that their inherent `any` (which returns
+    /// a `bool`) takes precedence in method call syntax.
+    fn any(self) -> Self::Mask;
+
+    /// Builds a wide value from exactly `LANES` elements.
+    #[inline]
+    fn from_lanes(lanes: &[Self::Element]) -> Self {
+        Self::gather(lanes, |e| *e)
+    }
+
+    /// Writes each lane into exactly `LANES` elements.
+    #[inline]
+    fn write_lanes(self, lanes: &mut [Self::Element]) {
+        self.scatter(lanes, |e| e)
+    }
+}
+
+/// Packs a slice of scalar elements into wide values.
+///
+/// If the length of `elements` is not a multiple of `W::LANES`, the unused lanes of the last wide value
+/// are filled with `fill`. Also returns the mask of valid lanes in the last wide value (all lanes are
+/// set if the slice divides evenly, and none are set if the slice is empty; every other wide value is
+/// always fully valid).
+pub fn pack<W: Lanes>(elements: &[W::Element], fill: W::Element) -> (Vec<W>, W::Mask) {
+    let mut wides = Vec::with_capacity(elements.chunks(W::LANES).len());
+    let mut valid = if elements.is_empty() { 0 } else { W::LANES };
+    for chunk in elements.chunks(W::LANES) {
+        if chunk.len() == W::LANES {
+            wides.push(W::from_lanes(chunk));
+        } else {
+            let mut padded = chunk.to_vec();
+            padded.resize(W::LANES, fill);
+            wides.push(W::from_lanes(&padded));
+            valid = chunk.len();
+        }
+    }
+    (wides, W::first_lanes_mask(valid))
+}
+
+/// Unpacks wide values back into a slice of scalar elements.
+///
+/// Exactly `elements.len()` lanes are written; any lanes past the end of `elements` (e.g. the padding added
+/// by [`pack`]) are ignored.
+///
+/// # Panics
+///
+/// Panics if `wides` contains fewer than `elements.len()` lanes in total.
+pub fn unpack<W: Lanes>(wides: &[W], elements: &mut [W::Element]) {
+    assert!(
+        wides.len() * W::LANES >= elements.len(),
+        "not enough wide values to fill the output slice"
+    );
+    for (wide, chunk) in wides.iter().zip(elements.chunks_mut(W::LANES)) {
+        if chunk.len() == W::LANES {
+            wide.write_lanes(chunk);
+        } else {
+            let mut padded = vec![chunk[0]; W::LANES];
+            wide.write_lanes(&mut padded);
+            chunk.copy_from_slice(&padded[..chunk.len()]);
+        }
+    }
+}
+
+macro_rules! impl_raw_lanes {
+    ($($tt:ident => ($t:ident, $l:expr, [$($i:expr),+])),+) => {
+        $(impl Lanes for $tt {
+            type Element = $t;
+            type Mask = $tt;
+            const LANES: usize = $l;
+
+            #[inline]
+            fn gather<T>(items: &[T], get: impl Fn(&T) -> $t) -> Self {
+                debug_assert_eq!(items.len(), $l);
+                let mut lanes = [0.0; $l];
+                for (lane, item) in lanes.iter_mut().zip(items.iter()) {
+                    *lane = get(item);
+                }
+                $tt::from(lanes)
+            }
+
+            #[inline]
+            fn scatter<T>(self, items: &mut [T], get: impl Fn(&mut T) -> &mut $t) {
+                debug_assert_eq!(items.len(), $l);
+                let lanes: [$t; $l] = self.into();
+                for (lane, item) in lanes.iter().zip(items.iter_mut()) {
+                    *get(item) = *lane;
+                }
+            }
+
+            #[inline]
+            fn first_lanes_mask(count: usize) -> $tt {
+                $tt::from([$($i as $t),+]).cmp_lt($tt::from(count as $t))
+            }
+
+            #[inline]
+            fn extract(self, lane: usize) -> $t {
+                let lanes: [$t; $l] = self.into();
+                lanes[lane]
+            }
+
+            #[inline]
+            fn replace(&mut self, lane: usize, value: $t) {
+                let mut lanes: [$t; $l] = (*self).into();
+                lanes[lane] = value;
+                *self = $tt::from(lanes);
+            }
+
+            #[inline]
+            fn merge(mask: $tt, tru: Self, fals: Self) -> Self {
+                mask.blend(tru, fals)
+            }
+
+            #[inline]
+            fn cmp_lt(self, rhs: Self) -> Self {
+                $tt::cmp_lt(self, rhs)
+            }
+
+            #[inline]
+            fn cmp_eq(self, rhs: Self) -> Self {
+                $tt::cmp_eq(self, rhs)
+            }
+
+            #[inline]
+            fn all(self) -> $tt {
+                self
+            }
+
+            #[inline]
+            fn any(self) -> $tt {
+                self
+            }
+        })+
+    };
+}
+
+impl_raw_lanes!(
+    f32x4 => (f32, 4, [0, 1, 2, 3]),
+    f32x8 => (f32, 8, [0, 1, 2, 3, 4, 5, 6, 7]),
+    f64x4 => (f64, 4, [0, 1, 2, 3])
+);
+
+macro_rules! impl_lanes {
+    ($($n:ident => ($e:ident, $tt:ident) { $($f:ident),+ }),+) => {
+        $(impl Lanes for $n {
+            type Element = $e;
+            type Mask = $tt;
+            const LANES: usize = <$tt as Lanes>::LANES;
+
+            #[inline]
+            fn gather<T>(items: &[T], get: impl Fn(&T) -> $e) -> Self {
+                Self {
+                    $($f: Lanes::gather(items, |item| get(item).$f)),+
+                }
+            }
+
+            #[inline]
+            fn scatter<T>(self, items: &mut [T], get: impl Fn(&mut T) -> &mut $e) {
+                $(self.$f.scatter(items, |item| &mut get(item).$f);)+
+            }
+
+            #[inline]
+            fn first_lanes_mask(count: usize) -> $tt {
+                <$tt as Lanes>::first_lanes_mask(count)
+            }
+
+            #[inline]
+            fn extract(self, lane: usize) -> $e {
+                $e {
+                    $($f: Lanes::extract(self.$f, lane)),+
+                }
+            }
+
+            #[inline]
+            fn replace(&mut self, lane: usize, value: $e) {
+                $(Lanes::replace(&mut self.$f, lane, value.$f);)+
+            }
+
+            #[inline]
+            fn merge(mask: $tt, tru: Self, fals: Self) -> Self {
+                Self {
+                    $($f: Lanes::merge(mask, tru.$f, fals.$f)),+
+                }
+            }
+
+            #[inline]
+            fn cmp_lt(self, rhs: Self) -> Self {
+                Self {
+                    $($f: Lanes::cmp_lt(self.$f, rhs.$f)),+
+                }
+            }
+
+            #[inline]
+            fn cmp_eq(self, rhs: Self) -> Self {
+                Self {
+                    $($f: Lanes::cmp_eq(self.$f, rhs.$f)),+
+                }
+            }
+
+            #[inline]
+            fn all(self) -> $tt {
+                $(Lanes::all(self.$f))&+
+            }
+
+            #[inline]
+            fn any(self) -> $tt {
+                $(Lanes::any(self.$f))|+
+            }
+        })+
+    };
+}
+
+macro_rules! impl_mat_lanes {
+    ($($n:ident => ($e:ident, $tt:ident) [$($i:expr),+]),+) => {
+        $(impl Lanes for $n {
+            type Element = $e;
+            type Mask = $tt;
+            const LANES: usize = <$tt as Lanes>::LANES;
+
+            #[inline]
+            fn gather<T>(items: &[T], get: impl Fn(&T) -> $e) -> Self {
+                Self {
+                    cols: [$(Lanes::gather(items, |item| get(item).cols[$i])),+],
+                }
+            }
+
+            #[inline]
+            fn scatter<T>(self, items: &mut [T], get: impl Fn(&mut T) -> &mut $e) {
+                $(self.cols[$i].scatter(items, |item| &mut get(item).cols[$i]);)+
+            }
+
+            #[inline]
+            fn first_lanes_mask(count: usize) -> $tt {
+                <$tt as Lanes>::first_lanes_mask(count)
+            }
+
+            #[inline]
+            fn extract(self, lane: usize) -> $e {
+                $e {
+                    cols: [$(self.cols[$i].extract(lane)),+],
+                }
+            }
+
+            #[inline]
+            fn replace(&mut self, lane: usize, value: $e) {
+                $(self.cols[$i].replace(lane, value.cols[$i]);)+
+            }
+
+            #[inline]
+            fn merge(mask: $tt, tru: Self, fals: Self) -> Self {
+                Self {
+                    cols: [$(Lanes::merge(mask, tru.cols[$i], fals.cols[$i])),+],
+                }
+            }
+
+            #[inline]
+            fn cmp_lt(self, rhs: Self) -> Self {
+                Self {
+                    cols: [$(Lanes::cmp_lt(self.cols[$i], rhs.cols[$i])),+],
+                }
+            }
+
+            #[inline]
+            fn cmp_eq(self, rhs: Self) -> Self {
+                Self {
+                    cols: [$(Lanes::cmp_eq(self.cols[$i], rhs.cols[$i])),+],
+                }
+            }
+
+            #[inline]
+            fn all(self) -> $tt {
+                $(Lanes::all(self.cols[$i]))&+
+            }
+
+            #[inline]
+            fn any(self) -> $tt {
+                $(Lanes::any(self.cols[$i]))|+
+            }
+        })+
+    };
+}
+
+impl_lanes!(
+    Wec2 => (Vec2, f32x4) { x, y },
+    Wec3 => (Vec3, f32x4) { x, y, z },
+    Wec4 => (Vec4, f32x4) { x, y, z, w },
+    WBivec2 => (Bivec2, f32x4) { xy },
+    WBivec3 => (Bivec3, f32x4) { xy, xz, yz },
+    WRotor2 => (Rotor2, f32x4) { s, bv },
+    WRotor3 => (Rotor3, f32x4) { s, bv },
+    WBivec4 => (Bivec4, f32x4) { xy, xz, xw, yz, yw, zw },
+    WRotor4 => (Rotor4, f32x4) { s, bv, xyzw },
+    WTrivec3 => (Trivec3, f32x4) { xyz },
+    WMultivec3 => (Multivec3, f32x4) { s, v, bv, tv },
+    WPoint3 => (Point3, f32x4) { e032, e013, e021, e123 },
+    WLine3 => (Line3, f32x4) { e01, e02, e03, e23, e31, e12 },
+    WPlane3 => (Plane3, f32x4) { e1, e2, e3, e0 },
+    WMotor3 => (Motor3, f32x4) { s, e23, e31, e12, e01, e02, e03, e0123 },
+    WIsometry2 => (Isometry2, f32x4) { translation, rotation },
+    WIsometry3 => (Isometry3, f32x4) { translation, rotation },
+    WSimilarity2 => (Similarity2, f32x4) { translation, rotation, scale },
+    WSimilarity3 => (Similarity3, f32x4) { translation, rotation, scale },
+    WAabb => (Aabb, f32x4) { min, max },
+    WSphere => (Sphere, f32x4) { center, radius },
+    WRay => (Ray, f32x4) { origin, direction },
+    WTriangle => (Triangle, f32x4) { a, b, c },
+    WAffine2 => (Affine2, f32x4) { translation, linear },
+    WAffine3 => (Affine3, f32x4) { translation, linear },
+
+    Wec2x8 => (Vec2, f32x8) { x, y },
+    Wec3x8 => (Vec3, f32x8) { x, y, z },
+    Wec4x8 => (Vec4, f32x8) { x, y, z, w },
+    WBivec2x8 => (Bivec2, f32x8) { xy },
+    WBivec3x8 => (Bivec3, f32x8) { xy, xz, yz },
+    WRotor2x8 => (Rotor2, f32x8) { s, bv },
+    WRotor3x8 => (Rotor3, f32x8) { s, bv },
+    WBivec4x8 => (Bivec4, f32x8) { xy, xz, xw, yz, yw, zw },
+    WRotor4x8 => (Rotor4, f32x8) { s, bv, xyzw },
+    WTrivec3x8 => (Trivec3, f32x8) { xyz },
+    WMultivec3x8 => (Multivec3, f32x8) { s, v, bv, tv },
+    WPoint3x8 => (Point3, f32x8) { e032, e013, e021, e123 },
+    WLine3x8 => (Line3, f32x8) { e01, e02, e03, e23, e31, e12 },
+    WPlane3x8 => (Plane3, f32x8) { e1, e2, e3, e0 },
+    WMotor3x8 => (Motor3, f32x8) { s, e23, e31, e12, e01, e02, e03, e0123 },
+    WIsometry2x8 => (Isometry2, f32x8) { translation, rotation },
+    WIsometry3x8 => (Isometry3, f32x8) { translation, rotation },
+    WSimilarity2x8 => (Similarity2, f32x8) { translation, rotation, scale },
+    WSimilarity3x8 => (Similarity3, f32x8) { translation, rotation, scale },
+    WAffine2x8 => (Affine2, f32x8) { translation, linear },
+    WAffine3x8 => (Affine3, f32x8) { translation, linear },
+
+    DWec2 => (DVec2, f64x4) { x, y },
+    DWec3 => (DVec3, f64x4) { x, y, z },
+    DWec4 => (DVec4, f64x4) { x, y, z, w },
+    DWBivec2 => (DBivec2, f64x4) { xy },
+    DWBivec3 => (DBivec3, f64x4) { xy, xz, yz },
+    DWRotor2 => (DRotor2, f64x4) { s, bv },
+    DWRotor3 => (DRotor3, f64x4) { s, bv },
+    DWBivec4 => (DBivec4, f64x4) { xy, xz, xw, yz, yw, zw },
+    DWRotor4 => (DRotor4, f64x4) { s, bv, xyzw },
+    DWTrivec3 => (DTrivec3, f64x4) { xyz },
+    DWMultivec3 => (DMultivec3, f64x4) { s, v, bv, tv },
+    DWPoint3 => (DPoint3, f64x4) { e032, e013, e021, e123 },
+    DWLine3 => (DLine3, f64x4) { e01, e02, e03, e23, e31, e12 },
+    DWPlane3 => (DPlane3, f64x4) { e1, e2, e3, e0 },
+    DWMotor3 => (DMotor3, f64x4) { s, e23, e31, e12, e01, e02, e03, e0123 },
+    DWIsometry2 => (DIsometry2, f64x4) { translation, rotation },
+    DWIsometry3 => (DIsometry3, f64x4) { translation, rotation },
+    DWSimilarity2 => (DSimilarity2, f64x4) { translation, rotation, scale },
+    DWSimilarity3 => (DSimilarity3, f64x4) { translation, rotation, scale },
+    DWAffine2 => (DAffine2, f64x4) { translation, linear },
+    DWAffine3 => (DAffine3, f64x4) { translation, linear }
+);
+
+impl_mat_lanes!(
+    Wat2 => (Mat2, f32x4) [0, 1],
+    Wat3 => (Mat3, f32x4) [0, 1, 2],
+    Wat4 => (Mat4, f32x4) [0, 1, 2, 3],
+    Wat2x8 => (Mat2, f32x8) [0, 1],
+    Wat3x8 => (Mat3, f32x8) [0, 1, 2],
+    Wat4x8 => (Mat4, f32x8) [0, 1, 2, 3],
+    DWat2 => (DMat2, f64x4) [0, 1],
+    DWat3 => (DMat3, f64x4) [0, 1, 2],
+    DWat4 => (DMat4, f64x4) [0, 1, 2, 3],
+    Wat3x2 => (Mat3x2, f32x4) [0, 1, 2],
+    Wat2x3 => (Mat2x3, f32x4) [0, 1],
+    Wat4x3 => (Mat4x3, f32x4) [0, 1, 2, 3],
+    Wat3x4 => (Mat3x4, f32x4) [0, 1, 2],
+    Wat3x2x8 => (Mat3x2, f32x8) [0, 1, 2],
+    Wat2x3x8 => (Mat2x3, f32x8) [0, 1],
+    Wat4x3x8 => (Mat4x3, f32x8) [0, 1, 2, 3],
+    Wat3x4x8 => (Mat3x4, f32x8) [0, 1, 2],
+    DWat3x2 => (DMat3x2, f64x4) [0, 1, 2],
+    DWat2x3 => (DMat2x3, f64x4) [0, 1],
+    DWat4x3 => (DMat4x3, f64x4) [0, 1, 2, 3],
+    DWat3x4 => (DMat3x4, f64x4) [0, 1, 2]
+);
+
+macro_rules! impl_normalize_masked {
+    ($($tt:ident => $($wt:ident),+);+) => {
+        $($(impl $wt {
+            /// Normalizes the lanes which are set in `mask`, leaving the other lanes untouched.
+            ///
+            /// This is useful when some lanes may have zero length, which would otherwise fill them
+            /// with NaN.
+            #[inline]
+            pub fn normalize_masked(&mut self, mask: $tt) {
+                *self = self.normalized_masked(mask);
+            }
+
+            #[inline]
+            pub fn normalized_masked(&self, mask: $tt) -> Self {
+                <Self as Lanes>::merge(mask, self.normalized(), *self)
+            }
+        })+)+
+    };
+}
+
+impl_normalize_masked!(
+    f32x4 => Wec2, Wec3, Wec4, WBivec2, WBivec3, WBivec4, WRotor2, WRotor3, WRotor4;
+    f32x8 => Wec2x8, Wec3x8, Wec4x8, WBivec2x8, WBivec3x8, WBivec4x8, WRotor2x8, WRotor3x8, WRotor4x8;
+    f64x4 => DWec2, DWec3, DWec4, DWBivec2, DWBivec3, DWBivec4, DWRotor2, DWRotor3, DWRotor4
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn pack_unpack_tail() {
+        let points: Vec<Vec3> = (0..7).map(|i| Vec3::new(i as f32, 1.0, -(i as f32))).collect();
+        let (wides, mask) = pack::<Wec3>(&points, Vec3::new(9.0, 9.0, 9.0));
+        assert_eq!(wides.len(), 2);
+        assert_eq!(mask.move_mask(), 0b0111);
+
+        let last: [Vec3; 4] = wides[1].into();
+        assert_eq!(last[3], Vec3::new(9.0, 9.0, 9.0));
+
+        let mut out = vec![Vec3::zero(); points.len()];
+        unpack(&wides, &mut out);
+        assert_eq!(out, points);
+    }
+
+    #[test]
+    fn pack_unpack_exact_and_empty() {
+        let points = [Vec2::new(1.0, 2.0); 8];
+        let (wides, mask) = pack::<Wec2x8>(&points, Vec2::zero());
+        assert_eq!(wides.len(), 1);
+        assert_eq!(mask.move_mask(), 0xff);
+
+        let (wides, mask) = pack::<Wec3>(&[], Vec3::zero());
+        assert!(wides.is_empty());
+        assert_eq!(mask.move_mask(), 0);
+    }
+
+    #[test]
+    fn pack_unpack_composite() {
+        let isometries: Vec<Isometry3> = (0..5)
+            .map(|i| {
+                Isometry3::new(
+                    Vec3::new(i as f32, 2.0, 3.0),
+                    Rotor3::from_rotation_xy(0.3 * i as f32),
+                )
+            })
+            .collect();
+        let (wides, _) = pack::<WIsometry3>(&isometries, Isometry3::identity());
+        let mut out = vec![Isometry3::identity(); isometries.len()];
+        unpack(&wides, &mut out);
+        for (a, b) in isometries.iter().zip(out.iter()) {
+            assert_eq!(a.translation, b.translation);
+            assert_eq!(a.rotation, b.rotation);
+        }
+
+        let mats = [Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0)); 3];
+        let (wides, mask) = pack::<Wat4>(&mats, Mat4::identity());
+        assert_eq!(mask.move_mask(), 0b0111);
+        let mut out = [Mat4::identity(); 3];
+        unpack(&wides, &mut out);
+        assert_eq!(out, mats);
+    }
+
+    #[test]
+    fn extract_replace() {
+        let mut w = WRotor3::from_lanes(&[Rotor3::identity(); 4]);
+        let r = Rotor3::from_rotation_xy(0.5);
+        w.replace(1, r);
+        assert_eq!(w.extract(1), r);
+        assert_eq!(w.extract(3), Rotor3::identity());
+
+        let mut m = Wat4x8::from_lanes(&[Mat4::identity(); 8]);
+        let t = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        m.replace(7, t);
+        assert_eq!(m.extract(7), t);
+        assert_eq!(m.extract(6), Mat4::identity());
+    }
+
+    #[test]
+    fn masked_compare_merge() {
+        let a = WBivec3::from_lanes(&[
+            Bivec3::new(1.0, 2.0, 3.0),
+            Bivec3::new(-1.0, 2.0, 3.0),
+            Bivec3::new(1.0, 5.0, 3.0),
+            Bivec3::new(0.0, 0.0, 0.0),
+        ]);
+        let b = WBivec3::from_lanes(&[Bivec3::new(1.0, 2.0, 3.0); 4]);
+
+        assert_eq!(a.cmp_eq(b).all().move_mask(), 0b0001);
+        assert_eq!(a.cmp_lt(b).any().move_mask(), 0b1010);
+        assert_eq!(a.cmp_lt(b).all().move_mask(), 0b1000);
+
+        let merged = WBivec3::merge(a.cmp_lt(b).any(), b, a);
+        assert_eq!(merged.cmp_eq(b).all().move_mask(), 0b1011);
+
+        let iso = WIsometry3::from_lanes(&[Isometry3::identity(); 4]);
+        let mut other = iso;
+        other.replace(2, Isometry3::new(Vec3::unit_x(), Rotor3::identity()));
+        assert_eq!(iso.cmp_eq(other).all().move_mask(), 0b1011);
+    }
+
+    #[test]
+    fn normalize_masked() {
+        let mut v = Wec3::from_lanes(&[
+            Vec3::new(3.0, 0.0, 4.0),
+            Vec3::zero(),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::zero(),
+        ]);
+        let nonzero = v.mag_sq().cmp_gt(f32x4::from(0.0));
+        v.normalize_masked(nonzero);
+        assert!(v.extract(0).eq_eps(Vec3::new(0.6, 0.0, 0.8)));
+        assert_eq!(v.extract(1), Vec3::zero());
+        assert_eq!(v.extract(2), Vec3::unit_y());
+
+        let rotor = Rotor3::from_rotation_xy(0.5);
+        let scaled = WRotor3::from_lanes(&[rotor * 2.0; 4]);
+        let normalized = scaled.normalized_masked(<WRotor3 as Lanes>::first_lanes_mask(1));
+        assert!(normalized.extract(0).eq_eps(rotor));
+        assert_eq!(normalized.extract(1), rotor * 2.0);
+    }
+}
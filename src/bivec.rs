@@ -32,7 +32,9 @@
 //! three components, each of which represents the *projected area* of that bivector onto one of the three
 //! basis bivectors. This is analogous to how vector components represent the *projected length* of that vector
 //! onto each unit vector.
-use wide::f32x4;
+//!
+//! In 4d, there are six basis planes (xy, xz, xw, yz, yw and zw), so a 4d bivector has six components.
+use wide::{f32x4, f32x8, f64x4};
 
 use crate::util::*;
 use crate::vec::*;
@@ -286,7 +288,13 @@ macro_rules! bivec2s {
     }
 }
 
-bivec2s!((Bivec2) => f32, (WBivec2) => f32x4);
+bivec2s!(
+    (Bivec2) => f32,
+    (WBivec2) => f32x4,
+    (WBivec2x8) => f32x8,
+    (DBivec2) => f64,
+    (DWBivec2) => f64x4
+);
 
 macro_rules! bivec3s {
     ($($bn:ident => ($vt:ident, $t:ident)),+) => {
@@ -335,6 +343,13 @@ macro_rules! bivec3s {
                 Self::new(v.z, v.y, v.x)
             }
 
+            /// The (Hodge) dual of this bivector, i.e. the vector normal to the plane it represents
+            /// and with the same magnitude, such that `a.wedge(b).dual() == a.cross(b)`.
+            #[inline]
+            pub fn dual(&self) -> $vt {
+                $vt::new(self.yz, -self.xz, self.xy)
+            }
+
             #[inline]
             pub fn unit_xy() -> Self {
                 Self::new($t::from(1.0), $t::from(0.0), $t::from(0.0))
@@ -573,7 +588,352 @@ macro_rules! bivec3s {
     }
 }
 
-bivec3s!(Bivec3 => (Vec3, f32), WBivec3 => (Wec3, f32x4));
+bivec3s!(
+    Bivec3 => (Vec3, f32),
+    WBivec3 => (Wec3, f32x4),
+    WBivec3x8 => (Wec3x8, f32x8),
+    DBivec3 => (DVec3, f64),
+    DWBivec3 => (DWec3, f64x4)
+);
+
+macro_rules! bivec4s {
+    ($($bn:ident => ($vt:ident, $t:ident)),+) => {
+        $(
+        /// A bivector in 4d space.
+        ///
+        /// In 4d, a bivector has 6 components, each one representing the signed *projected area* of the bivector
+        /// onto one of the 6 *basis bivectors*, which correspond to the six basis planes xy, xz, xw, yz, yw and zw.
+        /// Unlike in 3d, there is no longer a one-to-one correspondence between planes and axes: a plane in 4d is
+        /// "perpendicular" to another plane, not to an axis.
+        ///
+        /// Please see the module level documentation for more information on bivectors generally!
+        #[derive(Clone, Copy, Debug, Default)]
+        #[repr(C)]
+        pub struct $bn {
+            pub xy: $t,
+            pub xz: $t,
+            pub xw: $t,
+            pub yz: $t,
+            pub yw: $t,
+            pub zw: $t,
+        }
+
+        impl EqualsEps for $bn {
+            fn eq_eps(self, other: Self) -> bool {
+                self.xy.eq_eps(other.xy)
+                    && self.xz.eq_eps(other.xz)
+                    && self.xw.eq_eps(other.xw)
+                    && self.yz.eq_eps(other.yz)
+                    && self.yw.eq_eps(other.yw)
+                    && self.zw.eq_eps(other.zw)
+            }
+        }
+
+        impl $bn {
+            #[inline]
+            pub fn new(xy: $t, xz: $t, xw: $t, yz: $t, yw: $t, zw: $t) -> Self {
+                Self {
+                    xy, xz, xw, yz, yw, zw
+                }
+            }
+
+            #[inline]
+            pub fn zero() -> Self {
+                Self::new($t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0))
+            }
+
+            #[inline]
+            pub fn unit_xy() -> Self {
+                Self::new($t::from(1.0), $t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0))
+            }
+
+            #[inline]
+            pub fn unit_xz() -> Self {
+                Self::new($t::from(0.0), $t::from(1.0), $t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0))
+            }
+
+            #[inline]
+            pub fn unit_xw() -> Self {
+                Self::new($t::from(0.0), $t::from(0.0), $t::from(1.0), $t::from(0.0), $t::from(0.0), $t::from(0.0))
+            }
+
+            #[inline]
+            pub fn unit_yz() -> Self {
+                Self::new($t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(1.0), $t::from(0.0), $t::from(0.0))
+            }
+
+            #[inline]
+            pub fn unit_yw() -> Self {
+                Self::new($t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(1.0), $t::from(0.0))
+            }
+
+            #[inline]
+            pub fn unit_zw() -> Self {
+                Self::new($t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(0.0), $t::from(1.0))
+            }
+
+            #[inline]
+            pub fn mag_sq(&self) -> $t {
+                self.xy.mul_add(
+                    self.xy,
+                    self.xz.mul_add(
+                        self.xz,
+                        self.xw.mul_add(
+                            self.xw,
+                            self.yz.mul_add(self.yz, self.yw.mul_add(self.yw, self.zw * self.zw)),
+                        ),
+                    ),
+                )
+            }
+
+            #[inline]
+            pub fn mag(&self) -> $t {
+                self.mag_sq().sqrt()
+            }
+
+            #[inline]
+            pub fn normalize(&mut self) {
+                let mag = self.mag();
+                self.xy /= mag;
+                self.xz /= mag;
+                self.xw /= mag;
+                self.yz /= mag;
+                self.yw /= mag;
+                self.zw /= mag;
+            }
+
+            #[inline]
+            pub fn normalized(&self) -> Self {
+                let mut r = *self;
+                r.normalize();
+                r
+            }
+
+            #[inline]
+            pub fn layout() -> Layout {
+                Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+            }
+
+            #[inline]
+            pub fn as_slice(&self) -> &[$t] {
+                // This is safe because we are statically bounding our slices to the size of these
+                // vectors
+                unsafe {
+                    std::slice::from_raw_parts(self as *const $bn as *const $t, 6)
+                }
+            }
+
+            #[inline]
+            pub fn as_byte_slice(&self) -> &[u8] {
+                // This is safe because we are statically bounding our slices to the size of these
+                // vectors
+                unsafe {
+                    std::slice::from_raw_parts(self as *const $bn as *const u8, 6 * std::mem::size_of::<$t>())
+                }
+            }
+
+            #[inline]
+            pub fn as_mut_slice(&mut self) -> &mut [$t] {
+                // This is safe because we are statically bounding our slices to the size of these
+                // vectors
+                unsafe {
+                    std::slice::from_raw_parts_mut(self as *mut $bn as *mut $t, 6)
+                }
+            }
+
+            #[inline]
+            pub fn as_mut_byte_slice(&mut self) -> &mut [u8] {
+                // This is safe because we are statically bounding our slices to the size of these
+                // vectors
+                unsafe {
+                    std::slice::from_raw_parts_mut(self as *mut $bn as *mut u8, 6 * std::mem::size_of::<$t>())
+                }
+            }
+
+            /// Returns a constant unsafe pointer to the underlying data in the underlying type.
+            /// This function is safe because all types here are repr(C) and can be represented
+            /// as their underlying type.
+            ///
+            /// # Safety
+            ///
+            /// It is up to the caller to correctly use this pointer and its bounds.
+            #[inline]
+            pub fn as_ptr(&self) -> *const $t {
+                self as *const $bn as *const $t
+            }
+
+            /// Returns a mutable unsafe pointer to the underlying data in the underlying type.
+            /// This function is safe because all types here are repr(C) and can be represented
+            /// as their underlying type.
+            ///
+            /// # Safety
+            ///
+            /// It is up to the caller to correctly use this pointer and its bounds.
+            #[inline]
+            pub fn as_mut_ptr(&mut self) -> *mut $t {
+                self as *mut $bn as *mut $t
+            }
+        }
+
+        impl Add for $bn {
+            type Output = Self;
+            #[inline]
+            fn add(mut self, rhs: $bn) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl AddAssign for $bn {
+            #[inline]
+            fn add_assign(&mut self, rhs: $bn) {
+                self.xy += rhs.xy;
+                self.xz += rhs.xz;
+                self.xw += rhs.xw;
+                self.yz += rhs.yz;
+                self.yw += rhs.yw;
+                self.zw += rhs.zw;
+            }
+        }
+
+        impl Sub for $bn {
+            type Output = Self;
+            #[inline]
+            fn sub(mut self, rhs: $bn) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl SubAssign for $bn {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $bn) {
+                self.xy -= rhs.xy;
+                self.xz -= rhs.xz;
+                self.xw -= rhs.xw;
+                self.yz -= rhs.yz;
+                self.yw -= rhs.yw;
+                self.zw -= rhs.zw;
+            }
+        }
+
+        impl Mul for $bn {
+            type Output = Self;
+            #[inline]
+            fn mul(mut self, rhs: $bn) -> Self {
+                self *= rhs;
+                self
+            }
+        }
+
+        impl Mul<$bn> for $t {
+            type Output = $bn;
+            #[inline]
+            fn mul(self, mut rhs: $bn) -> $bn {
+                rhs *= self;
+                rhs
+            }
+        }
+
+        impl Mul<$t> for $bn {
+            type Output = Self;
+            #[inline]
+            fn mul(mut self, rhs: $t) -> Self {
+                self *= rhs;
+                self
+            }
+        }
+
+        impl MulAssign for $bn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                self.xy *= rhs.xy;
+                self.xz *= rhs.xz;
+                self.xw *= rhs.xw;
+                self.yz *= rhs.yz;
+                self.yw *= rhs.yw;
+                self.zw *= rhs.zw;
+            }
+        }
+
+        impl MulAssign<$t> for $bn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                self.xy *= rhs;
+                self.xz *= rhs;
+                self.xw *= rhs;
+                self.yz *= rhs;
+                self.yw *= rhs;
+                self.zw *= rhs;
+            }
+        }
+
+        impl Div for $bn {
+            type Output = Self;
+            #[inline]
+            fn div(mut self, rhs: $bn) -> Self {
+                self /= rhs;
+                self
+            }
+        }
+
+        impl Div<$t> for $bn {
+            type Output = $bn;
+            #[inline]
+            fn div(mut self, rhs: $t) -> $bn {
+                self /= rhs;
+                self
+            }
+        }
+
+        impl DivAssign for $bn {
+            #[inline]
+            fn div_assign(&mut self, rhs: $bn) {
+                self.xy /= rhs.xy;
+                self.xz /= rhs.xz;
+                self.xw /= rhs.xw;
+                self.yz /= rhs.yz;
+                self.yw /= rhs.yw;
+                self.zw /= rhs.zw;
+            }
+        }
+
+        impl DivAssign<$t> for $bn {
+            #[inline]
+            fn div_assign(&mut self, rhs: $t) {
+                self.xy /= rhs;
+                self.xz /= rhs;
+                self.xw /= rhs;
+                self.yz /= rhs;
+                self.yw /= rhs;
+                self.zw /= rhs;
+            }
+        }
+
+        impl Neg for $bn {
+            type Output = Self;
+            #[inline]
+            fn neg(mut self) -> Self {
+                self.xy = -self.xy;
+                self.xz = -self.xz;
+                self.xw = -self.xw;
+                self.yz = -self.yz;
+                self.yw = -self.yw;
+                self.zw = -self.zw;
+                self
+            }
+        }
+        )+
+    }
+}
+
+bivec4s!(
+    Bivec4 => (Vec4, f32),
+    WBivec4 => (Wec4, f32x4),
+    WBivec4x8 => (Wec4x8, f32x8),
+    DBivec4 => (DVec4, f64),
+    DWBivec4 => (DWec4, f64x4)
+);
 
 impl PartialEq for Bivec2 {
     fn eq(&self, other: &Self) -> bool {
@@ -594,3 +954,43 @@ impl PartialEq for Bivec3 {
         self.xy != other.xy || self.xz != other.xz || self.yz != other.yz
     }
 }
+
+impl PartialEq for DBivec2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.xy == other.xy
+    }
+}
+
+impl PartialEq for DBivec3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.xy == other.xy && self.xz == other.xz && self.yz == other.yz
+    }
+}
+
+impl PartialEq for Bivec4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.xy == other.xy
+            && self.xz == other.xz
+            && self.xw == other.xw
+            && self.yz == other.yz
+            && self.yw == other.yw
+            && self.zw == other.zw
+    }
+}
+
+impl PartialEq for DBivec4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.xy == other.xy
+            && self.xz == other.xz
+            && self.xw == other.xw
+            && self.yz == other.yz
+            && self.yw == other.yw
+            && self.zw == other.zw
+    }
+}
+
+impl_f64_conversions!(
+    Bivec2 => DBivec2 { xy },
+    Bivec3 => DBivec3 { xy, xz, yz },
+    Bivec4 => DBivec4 { xy, xz, xw, yz, yw, zw }
+);
@@ -0,0 +1,440 @@
+//! Decompositions of 2x2 and 3x3 matrices, and of 4x4 transformation matrices.
+//!
+//! All of the decompositions are computed without branching on the contents of the matrix (using
+//! a fixed number of Jacobi rotations where an iterative method is needed), so the wide matrix types
+//! decompose every lane at once, with the same results as the scalar types.
+use wide::{f32x4, f32x8, f64x4};
+
+use crate::util::*;
+use crate::*;
+
+/// The number of cyclic Jacobi sweeps used by the 3x3 eigen and singular value decompositions.
+///
+/// Jacobi iteration converges quadratically, so this is enough for 3x3 matrices in both single and double precision.
+const JACOBI_SWEEPS: usize = 6;
+
+/// The pairs of columns which are rotated, in order, by each Jacobi sweep.
+const JACOBI_PAIRS: [(usize, usize); 3] = [(0, 1), (0, 2), (1, 2)];
+
+/// Select between `$t` and `$f` based on whether `$a < $b`.
+macro_rules! select_scalar {
+    ($a:expr, $b:expr, $t:expr, $f:expr) => {
+        if $a < $b {
+            $t
+        } else {
+            $f
+        }
+    };
+}
+
+/// Select between `$t` and `$f` in each lane based on whether `$a < $b` in that lane.
+macro_rules! select_wide {
+    ($a:expr, $b:expr, $t:expr, $f:expr) => {
+        Lanes::merge($a.cmp_lt($b), $t, $f)
+    };
+}
+
+macro_rules! decompose2s {
+    ($($sel:ident => $n:ident, $vt:ident, $t:ident),+) => {
+        $(impl $n {
+            /// The eigen decomposition of this matrix, which must be symmetric. Only the
+            /// lower triangle (i.e. `cols[0].y`) is used for the off-diagonal element.
+            ///
+            /// Returns the eigenvalues, sorted from largest to smallest, and a rotation matrix whose
+            /// columns are the corresponding normalized eigenvectors, such that
+            /// `self == vectors * Mat2::from_nonuniform_scale(values) * vectors.transposed()`.
+            #[inline]
+            pub fn symmetric_eigen(&self) -> ($vt, Self) {
+                let (a, b, d) = (self.cols[0].x, self.cols[0].y, self.cols[1].y);
+                let (s, c) = ($t::from(0.5) * ($t::from(2.0) * b).atan2(d - a)).sin_cos();
+                let v0 = $vt::new(c, -s);
+                let v1 = $vt::new(s, c);
+                let (cc, ss, cs2b) = (c * c, s * s, $t::from(2.0) * c * s * b);
+                let l0 = cc * a - cs2b + ss * d;
+                let l1 = ss * a + cs2b + cc * d;
+
+                // swapping the columns would reflect, so negate one to keep a rotation
+                let (l0, l1, v0, v1) = (
+                    $sel!(l0, l1, l1, l0),
+                    $sel!(l0, l1, l0, l1),
+                    $sel!(l0, l1, v1, v0),
+                    $sel!(l0, l1, -v0, v1),
+                );
+                ($vt::new(l0, l1), Self::new(v0, v1))
+            }
+
+            /// The singular value decomposition of this matrix.
+            ///
+            /// Returns `(u, sigma, v)` such that `self == u * Mat2::from_nonuniform_scale(sigma) * v.transposed()`,
+            /// where `u` and `v` are rotation matrices. So that both are always rotations, the
+            /// last singular value is negative if this matrix contains a reflection. The singular values
+            /// are sorted from largest to smallest by absolute value.
+            #[inline]
+            pub fn svd(&self) -> (Self, $vt, Self) {
+                let (a0, a1) = (self.cols[0], self.cols[1]);
+                let (s, c) = ($t::from(0.5) * ($t::from(2.0) * a0.dot(a1)).atan2(a1.mag_sq() - a0.mag_sq())).sin_cos();
+                let c0 = a0 * c - a1 * s;
+                let c1 = a0 * s + a1 * c;
+                let v0 = $vt::new(c, -s);
+                let v1 = $vt::new(s, c);
+
+                let (m0, m1) = (c0.mag_sq(), c1.mag_sq());
+                let (c0, c1, v0, v1) = (
+                    $sel!(m0, m1, c1, c0),
+                    $sel!(m0, m1, -c0, c1),
+                    $sel!(m0, m1, v1, v0),
+                    $sel!(m0, m1, -v0, v1),
+                );
+
+                let sigma0 = c0.mag();
+                let u0 = $sel!(sigma0, $t::from(1e-30), $vt::unit_x(), c0 / sigma0);
+                let u1 = $vt::new(-u0.y, u0.x);
+                (Self::new(u0, u1), $vt::new(sigma0, c1.dot(u1)), Self::new(v0, v1))
+            }
+
+            /// The polar decomposition of this matrix, i.e. the rotation matrix `r` and the symmetric
+            /// stretch matrix `s` such that `self == r * s`.
+            ///
+            /// If this matrix contains a reflection, `s` will have a negative eigenvalue, so that `r` is always
+            /// a rotation.
+            #[inline]
+            pub fn polar(&self) -> (Self, Self) {
+                let (u, sigma, v) = self.svd();
+                let vt = v.transposed();
+                (u * vt, v * Self::from_nonuniform_scale(sigma) * vt)
+            }
+
+            /// The QR decomposition of this matrix, i.e. the rotation matrix `q` and the upper
+            /// triangular matrix `r` such that `self == q * r`.
+            ///
+            /// The diagonal of `r` is positive, except for the last element, which is negative
+            /// if this matrix contains a reflection.
+            #[inline]
+            pub fn qr(&self) -> (Self, Self) {
+                let (a0, a1) = (self.cols[0], self.cols[1]);
+                let r00 = a0.mag();
+                let q0 = $sel!(r00, $t::from(1e-30), $vt::unit_x(), a0 / r00);
+                let q1 = $vt::new(-q0.y, q0.x);
+                (
+                    Self::new(q0, q1),
+                    Self::new($vt::new(r00, $t::from(0.0)), $vt::new(q0.dot(a1), q1.dot(a1))),
+                )
+            }
+        })+
+    };
+}
+
+decompose2s!(
+    select_scalar => Mat2, Vec2, f32,
+    select_wide => Wat2, Wec2, f32x4,
+    select_wide => Wat2x8, Wec2x8, f32x8,
+    select_scalar => DMat2, DVec2, f64,
+    select_wide => DWat2, DWec2, f64x4
+);
+
+macro_rules! decompose3s {
+    ($($sel:ident => $n:ident, $vt:ident, $t:ident),+) => {
+        $(impl $n {
+            /// The eigen decomposition of this matrix, which must be symmetric. This uses the cyclic
+            /// Jacobi method, so it is accurate even for nearly repeated eigenvalues.
+            ///
+            /// Returns the eigenvalues, sorted from largest to smallest, and a matrix whose
+            /// columns are the corresponding normalized (and mutually orthogonal) eigenvectors, such that
+            /// `self == vectors * Mat3::from_nonuniform_scale(values) * vectors.transposed()`.
+            pub fn symmetric_eigen(&self) -> ($vt, Self) {
+                let mut a = [
+                    [self.cols[0].x, self.cols[0].y, self.cols[0].z],
+                    [self.cols[1].x, self.cols[1].y, self.cols[1].z],
+                    [self.cols[2].x, self.cols[2].y, self.cols[2].z],
+                ];
+                let mut v = Self::identity().cols;
+
+                for _ in 0..JACOBI_SWEEPS {
+                    for &(p, q) in JACOBI_PAIRS.iter() {
+                        // the rotation in the pq plane which zeroes a[p][q]
+                        let (s, c) = ($t::from(0.5) * ($t::from(2.0) * a[p][q]).atan2(a[q][q] - a[p][p])).sin_cos();
+                        for row in a.iter_mut() {
+                            let (ap, aq) = (row[p], row[q]);
+                            row[p] = c * ap - s * aq;
+                            row[q] = s * ap + c * aq;
+                        }
+                        let (ap, aq) = (a[p], a[q]);
+                        for k in 0..3 {
+                            a[p][k] = c * ap[k] - s * aq[k];
+                            a[q][k] = s * ap[k] + c * aq[k];
+                        }
+                        let (vp, vq) = (v[p], v[q]);
+                        v[p] = vp * c - vq * s;
+                        v[q] = vp * s + vq * c;
+                    }
+                }
+
+                let mut l = [a[0][0], a[1][1], a[2][2]];
+                for &(i, j) in [(0, 1), (1, 2), (0, 1)].iter() {
+                    let (li, lj, vi, vj) = (l[i], l[j], v[i], v[j]);
+                    l[i] = $sel!(li, lj, lj, li);
+                    l[j] = $sel!(li, lj, li, lj);
+                    v[i] = $sel!(li, lj, vj, vi);
+                    v[j] = $sel!(li, lj, vi, vj);
+                }
+                ($vt::new(l[0], l[1], l[2]), Self::new(v[0], v[1], v[2]))
+            }
+
+            /// The singular value decomposition of this matrix. This uses the one-sided Jacobi method.
+            ///
+            /// Returns `(u, sigma, v)` such that `self == u * Mat3::from_nonuniform_scale(sigma) * v.transposed()`,
+            /// where `u` and `v` are rotation matrices. So that both are always rotations, the
+            /// last singular value is negative if this matrix contains a reflection. The singular values
+            /// are sorted from largest to smallest by absolute value.
+            pub fn svd(&self) -> (Self, $vt, Self) {
+                let mut c = self.cols;
+                let mut v = Self::identity().cols;
+
+                for _ in 0..JACOBI_SWEEPS {
+                    for &(p, q) in JACOBI_PAIRS.iter() {
+                        // the rotation which makes columns p and q orthogonal
+                        let (alpha, beta, gamma) = (c[p].mag_sq(), c[q].mag_sq(), c[p].dot(c[q]));
+                        let (s, cos) = ($t::from(0.5) * ($t::from(2.0) * gamma).atan2(beta - alpha)).sin_cos();
+                        let (cp, cq) = (c[p], c[q]);
+                        c[p] = cp * cos - cq * s;
+                        c[q] = cp * s + cq * cos;
+                        let (vp, vq) = (v[p], v[q]);
+                        v[p] = vp * cos - vq * s;
+                        v[q] = vp * s + vq * cos;
+                    }
+                }
+
+                let mut m = [c[0].mag_sq(), c[1].mag_sq(), c[2].mag_sq()];
+                for &(i, j) in [(0, 1), (1, 2), (0, 1)].iter() {
+                    let (mi, mj, ci, cj, vi, vj) = (m[i], m[j], c[i], c[j], v[i], v[j]);
+                    m[i] = $sel!(mi, mj, mj, mi);
+                    m[j] = $sel!(mi, mj, mi, mj);
+                    c[i] = $sel!(mi, mj, cj, ci);
+                    c[j] = $sel!(mi, mj, ci, cj);
+                    v[i] = $sel!(mi, mj, vj, vi);
+                    v[j] = $sel!(mi, mj, vi, vj);
+                }
+
+                // make v a rotation, flipping the sign of the smallest singular value instead
+                let flip = $sel!(v[0].dot(v[1].cross(v[2])), $t::from(0.0), $t::from(-1.0), $t::from(1.0));
+                v[2] *= flip;
+                c[2] *= flip;
+
+                let sigma0 = m[0].sqrt();
+                let u0 = $sel!(sigma0, $t::from(1e-30), $vt::unit_x(), c[0] / sigma0);
+                let u1 = Self::orthonormal_to(u0, c[1]);
+                let u2 = u0.cross(u1);
+                (
+                    Self::new(u0, u1, u2),
+                    $vt::new(sigma0, c[1].dot(u1), c[2].dot(u2)),
+                    Self::new(v[0], v[1], v[2]),
+                )
+            }
+
+            /// The polar decomposition of this matrix, i.e. the rotation matrix `r` and the symmetric
+            /// stretch matrix `s` such that `self == r * s`.
+            ///
+            /// If this matrix contains a reflection, `s` will have a negative eigenvalue, so that `r` is always
+            /// a rotation, as is usually desired for deformation gradients in simulations.
+            #[inline]
+            pub fn polar(&self) -> (Self, Self) {
+                let (u, sigma, v) = self.svd();
+                let vt = v.transposed();
+                (u * vt, v * Self::from_nonuniform_scale(sigma) * vt)
+            }
+
+            /// The QR decomposition of this matrix, i.e. the rotation matrix `q` and the upper
+            /// triangular matrix `r` such that `self == q * r`.
+            ///
+            /// The diagonal of `r` is positive, except for the last element, which is negative
+            /// if this matrix contains a reflection.
+            #[inline]
+            pub fn qr(&self) -> (Self, Self) {
+                let (a0, a1, a2) = (self.cols[0], self.cols[1], self.cols[2]);
+                let r00 = a0.mag();
+                let q0 = $sel!(r00, $t::from(1e-30), $vt::unit_x(), a0 / r00);
+                let q1 = Self::orthonormal_to(q0, a1);
+                let q2 = q0.cross(q1);
+                let zero = $t::from(0.0);
+                (
+                    Self::new(q0, q1, q2),
+                    Self::new(
+                        $vt::new(r00, zero, zero),
+                        $vt::new(q0.dot(a1), q1.dot(a1), zero),
+                        $vt::new(q0.dot(a2), q1.dot(a2), q2.dot(a2)),
+                    ),
+                )
+            }
+
+            /// The normalized part of `v` which is orthogonal to the normalized vector `n`, or some other
+            /// normalized vector orthogonal to `n` if `v` is parallel to it.
+            #[inline]
+            fn orthonormal_to(n: $vt, v: $vt) -> $vt {
+                let fallback = $sel!(n.x.abs(), $t::from(0.5), $vt::unit_x(), $vt::unit_y());
+                let w = v - n * n.dot(v);
+                let w = $sel!(w.mag_sq(), $t::from(1e-30), fallback - n * n.dot(fallback), w);
+                w.normalized()
+            }
+        })+
+    };
+}
+
+decompose3s!(
+    select_scalar => Mat3, Vec3, f32,
+    select_wide => Wat3, Wec3, f32x4,
+    select_wide => Wat3x8, Wec3x8, f32x8,
+    select_scalar => DMat3, DVec3, f64,
+    select_wide => DWat3, DWec3, f64x4
+);
+
+macro_rules! decompose_trs {
+    ($($sel:ident => $n:ident, $m3t:ident, $rt:ident, $v3t:ident, $t:ident),+) => {
+        $(impl $n {
+            /// Decompose this matrix, which must be an affine transformation, into a translation,
+            /// a rotation and a non-uniform scale, such that `Mat4::from_trs(translation, rotation, scale)`
+            /// reproduces it. This is useful for matrices from model formats such as glTF or FBX.
+            ///
+            /// If this matrix contains a reflection, the x component of the scale is negative. Any shear
+            /// is discarded, and the rotation is arbitrary around axes with zero scale.
+            pub fn decompose_trs(&self) -> ($v3t, $rt, $v3t) {
+                let mut linear = $m3t::new(self.cols[0].xyz(), self.cols[1].xyz(), self.cols[2].xyz());
+                let flip = $sel!(linear.determinant(), $t::from(0.0), $t::from(-1.0), $t::from(1.0));
+                linear.cols[0] *= flip;
+                let scale = $v3t::new(linear.cols[0].mag() * flip, linear.cols[1].mag(), linear.cols[2].mag());
+                let (rotation, _) = linear.qr();
+                (self.cols[3].xyz(), $rt::from_rotation_matrix(rotation), scale)
+            }
+        })+
+    };
+}
+
+decompose_trs!(
+    select_scalar => Mat4, Mat3, Rotor3, Vec3, f32,
+    select_wide => Wat4, Wat3, WRotor3, Wec3, f32x4,
+    select_wide => Wat4x8, Wat3x8, WRotor3x8, Wec3x8, f32x8,
+    select_scalar => DMat4, DMat3, DRotor3, DVec3, f64,
+    select_wide => DWat4, DWat3, DWRotor3, DWec3, f64x4
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn mat3_eq_eps(a: Mat3, b: Mat3) -> bool {
+        a.cols.iter().zip(b.cols.iter()).all(|(a, b)| a.eq_eps(*b))
+    }
+
+    fn mat2_eq_eps(a: Mat2, b: Mat2) -> bool {
+        a.cols.iter().zip(b.cols.iter()).all(|(a, b)| a.eq_eps(*b))
+    }
+
+    fn is_rotation(m: Mat3) -> bool {
+        mat3_eq_eps(m * m.transposed(), Mat3::identity()) && m.determinant().eq_eps(1.0)
+    }
+
+    #[test]
+    fn symmetric_eigen3() {
+        let r = Rotor3::from_euler_angles(0.3, -0.8, 1.2).into_matrix();
+        let m = r * Mat3::from_nonuniform_scale(Vec3::new(2.0, 5.0, -1.0)) * r.transposed();
+        let (values, vectors) = m.symmetric_eigen();
+        assert!(values.eq_eps(Vec3::new(5.0, 2.0, -1.0)));
+        assert!(mat3_eq_eps(vectors * Mat3::from_nonuniform_scale(values) * vectors.transposed(), m));
+        assert!(mat3_eq_eps(vectors.transposed() * vectors, Mat3::identity()));
+
+        let (values, vectors) = Mat3::from_scale(3.0).symmetric_eigen();
+        assert!(values.eq_eps(Vec3::broadcast(3.0)));
+        assert!(mat3_eq_eps(vectors, Mat3::identity()));
+    }
+
+    #[test]
+    fn svd_and_polar3() {
+        let m = Mat3::new(Vec3::new(2.0, 0.5, -1.0), Vec3::new(0.3, 1.0, 2.0), Vec3::new(-1.0, 0.2, 0.7));
+        let (u, sigma, v) = m.svd();
+        assert!(is_rotation(u) && is_rotation(v));
+        assert!(sigma.x >= sigma.y && sigma.y >= sigma.z.abs());
+        assert!(mat3_eq_eps(u * Mat3::from_nonuniform_scale(sigma) * v.transposed(), m));
+
+        let reflection = m * Mat3::from_nonuniform_scale(Vec3::new(1.0, -1.0, 1.0));
+        let (rotation, stretch) = reflection.polar();
+        assert!(is_rotation(rotation));
+        assert!(mat3_eq_eps(stretch, stretch.transposed()));
+        assert!(mat3_eq_eps(rotation * stretch, reflection));
+
+        let rank_one = Mat3::new(Vec3::new(1.0, 2.0, 3.0), Vec3::zero(), Vec3::new(2.0, 4.0, 6.0));
+        let (u, sigma, v) = rank_one.svd();
+        assert!(is_rotation(u) && is_rotation(v));
+        assert!(mat3_eq_eps(u * Mat3::from_nonuniform_scale(sigma) * v.transposed(), rank_one));
+    }
+
+    #[test]
+    fn qr3() {
+        let m = Mat3::new(Vec3::new(2.0, 0.5, -1.0), Vec3::new(0.3, 1.0, 2.0), Vec3::new(-1.0, 0.2, 0.7));
+        let (q, r) = m.qr();
+        assert!(is_rotation(q));
+        assert!(r.cols[0].y == 0.0 && r.cols[0].z == 0.0 && r.cols[1].z == 0.0);
+        assert!(mat3_eq_eps(q * r, m));
+    }
+
+    #[test]
+    fn decompose2() {
+        let m = Mat2::new(Vec2::new(2.0, 0.5), Vec2::new(-1.0, 0.3));
+        let (u, sigma, v) = m.svd();
+        assert!(u.determinant().eq_eps(1.0) && v.determinant().eq_eps(1.0));
+        assert!(mat2_eq_eps(u * Mat2::from_nonuniform_scale(sigma) * v.transposed(), m));
+
+        let (rotation, stretch) = m.polar();
+        assert!(mat2_eq_eps(rotation * rotation.transposed(), Mat2::identity()));
+        assert!(mat2_eq_eps(rotation * stretch, m));
+
+        let (q, r) = m.qr();
+        assert!(r.cols[0].y == 0.0);
+        assert!(mat2_eq_eps(q * r, m));
+
+        let symmetric = Mat2::new(Vec2::new(2.0, 1.0), Vec2::new(1.0, 2.0));
+        let (values, vectors) = symmetric.symmetric_eigen();
+        assert!(values.eq_eps(Vec2::new(3.0, 1.0)));
+        assert!(mat2_eq_eps(vectors * Mat2::from_nonuniform_scale(values) * vectors.transposed(), symmetric));
+
+        // only the lower triangle is read
+        let (lower, _) = Mat2::new(Vec2::new(2.0, 1.0), Vec2::new(0.0, 3.0)).symmetric_eigen();
+        assert!(lower.eq_eps(Vec2::new(3.618, 1.382)));
+    }
+
+    #[test]
+    fn trs() {
+        let t = Vec3::new(1.0, -2.0, 3.0);
+        let r = Rotor3::from_euler_angles(0.3, -0.8, 1.2);
+        let s = Vec3::new(2.0, 0.5, 3.0);
+        let (dt, dr, ds) = Mat4::from_trs(t, r, s).decompose_trs();
+        assert!(dt.eq_eps(t) && dr.eq_eps(r) && ds.eq_eps(s));
+
+        let mirrored = Mat4::from_trs(t, r, s) * Mat4::from_nonuniform_scale(Vec4::new(1.0, 1.0, -1.0, 1.0));
+        let (dt, dr, ds) = mirrored.decompose_trs();
+        assert!(dt.eq_eps(t) && ds.x < 0.0);
+        let rebuilt = Mat4::from_trs(dt, dr, ds);
+        assert!(rebuilt.cols.iter().zip(mirrored.cols.iter()).all(|(a, b)| a.eq_eps(*b)));
+
+        let wide = Wat4::from_lanes(&[mirrored; 4]).decompose_trs();
+        assert!(wide.1.extract(2).eq_eps(dr) && wide.2.extract(2).eq_eps(ds));
+    }
+
+    #[test]
+    fn wide_decompose3() {
+        let a = Mat3::new(Vec3::new(2.0, 0.5, -1.0), Vec3::new(0.3, 1.0, 2.0), Vec3::new(-1.0, 0.2, 0.7));
+        let b = Mat3::from_nonuniform_scale(Vec3::new(1.0, 3.0, 2.0));
+        let wide = Wat3::from_lanes(&[a, b, a, b]);
+
+        let (u, sigma, v) = wide.svd();
+        let (su, ssigma, sv) = b.svd();
+        assert!(mat3_eq_eps(u.extract(1), su) && sigma.extract(1).eq_eps(ssigma) && mat3_eq_eps(v.extract(1), sv));
+
+        let (rotation, stretch) = wide.polar();
+        let (srotation, sstretch) = a.polar();
+        assert!(mat3_eq_eps(rotation.extract(2), srotation) && mat3_eq_eps(stretch.extract(2), sstretch));
+
+        let symmetric = a * a.transposed();
+        let (values, vectors) = Wat3::from_lanes(&[symmetric; 4]).symmetric_eigen();
+        let (svalues, svectors) = symmetric.symmetric_eigen();
+        assert!(values.extract(3).eq_eps(svalues) && mat3_eq_eps(vectors.extract(3), svectors));
+    }
+}
@@ -1,6 +1,8 @@
 //!
 //! Geometry helper functionality.
-use crate::{Vec3, Vec3i, Vec3u};
+use crate::{Mat4, Vec3, Vec3i, Vec3u, Vec4, Wec3};
+
+use wide::f32x4;
 
 /// A plane which can be intersected by a ray.
 #[derive(Debug, Copy, Clone)]
@@ -197,6 +199,44 @@ impl_plane_ray!(Plane, Ray, Vec3 => f32);
 impl_plane_ray!(Planeu, Rayu, Vec3u => u32);
 impl_plane_ray!(Planei, Rayi, Vec3i => i32);
 
+impl Ray {
+    /// Returns the distances along the ray at which it enters and exits the provided `Aabb`, or `None` if
+    /// it misses the box. The entry distance is negative if the ray starts inside the box.
+    #[inline]
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<(f32, f32)> {
+        let inv_dir = Vec3::one() / self.direction;
+        let (x0, x1) = slab(self.origin.x, inv_dir.x, aabb.min.x, aabb.max.x);
+        let (y0, y1) = slab(self.origin.y, inv_dir.y, aabb.min.y, aabb.max.y);
+        let (z0, z1) = slab(self.origin.z, inv_dir.z, aabb.min.z, aabb.max.z);
+        let entry = x0.max(y0).max(z0);
+        let exit = x1.min(y1).min(z1);
+        if exit >= entry.max(0.0) {
+            Some((entry, exit))
+        } else {
+            None
+        }
+    }
+
+    /// Intersect this ray with four boxes at once, e.g. the children of a BVH node.
+    ///
+    /// Returns a lane mask which is set for the boxes that are hit, and the entry and exit
+    /// distances as in `intersect_aabb`, which are only meaningful in the lanes that are hit.
+    #[inline]
+    pub fn intersect_aabbs(&self, aabbs: &WAabb) -> (f32x4, f32x4, f32x4) {
+        let inv_dir = Wec3::splat(Vec3::one() / self.direction);
+        slab_test(Wec3::splat(self.origin), inv_dir, aabbs.min, aabbs.max)
+    }
+}
+
+impl Plane {
+    /// Returns the signed distance from this `Plane` to the provided point, which is positive on the side
+    /// the normal points towards. The plane must be normalized.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) - self.bias
+    }
+}
+
 /// An axis-aligned bounding box
 #[derive(Default, Debug, Copy, Clone)]
 #[repr(C)]
@@ -336,6 +376,19 @@ impl Aabb {
     pub fn iter(&self) -> AabbLinearIterator {
         self.iter_stride(1.0)
     }
+
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Half of the size of this box along each axis, i.e. the distance from the center to the faces.
+    #[inline]
+    #[must_use]
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
 }
 
 impl Aabbu {
@@ -357,3 +410,808 @@ impl Aabbi {
 }
 
 impl_aabb!(Aabb, AabbLinearIterator, Vec3 => f32, Aabbu, AabbuLinearIterator, Vec3u => u32, Aabbi, AabbiLinearIterator, Vec3i => i32);
+
+/// A sphere, e.g. to be used as a bounding volume.
+#[derive(Default, Debug, Copy, Clone)]
+#[repr(C)]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl Sphere {
+    #[inline]
+    #[must_use]
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, target: Vec3) -> bool {
+        (target - self.center).mag_sq() <= self.radius * self.radius
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let radii = self.radius + other.radius;
+        (other.center - self.center).mag_sq() <= radii * radii
+    }
+}
+
+/// A view frustum, i.e. the volume visible through a camera, bounded by six planes.
+///
+/// The normals of all planes point towards the inside of the frustum, and are normalized, so that
+/// `Plane::signed_distance` is positive for points inside. The planes are named after the sides of the
+/// clip space volume, so e.g. `top` is the plane where the clip space `y == w`, which is at the bottom
+/// of the screen for y-down projections.
+///
+/// If the far plane is at infinity (as with the `perspective_infinite_z_*` projections),
+/// `far` has a zero normal and an infinitely negative bias, so that it never culls anything.
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct Frustum {
+    pub left: Plane,
+    pub right: Plane,
+    pub bottom: Plane,
+    pub top: Plane,
+    pub near: Plane,
+    pub far: Plane,
+}
+
+impl Frustum {
+    /// Extract the frustum from a view-projection (or just a projection) matrix whose clip space depth
+    /// extends from -1.0 (near) to 1.0 (far), such as the `projection::*_gl` matrices.
+    ///
+    /// The planes are in the space the matrix transforms from, e.g. world space for a view-projection matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_matrix_gl(view_projection: Mat4) -> Self {
+        let rows = view_projection.transposed().cols;
+        Self::from_clip_rows(rows, rows[3] + rows[2], rows[3] - rows[2])
+    }
+
+    /// Extract the frustum from a view-projection (or just a projection) matrix whose clip space depth
+    /// extends from 0.0 (near) to 1.0 (far), such as the `projection::*_vk` matrices.
+    ///
+    /// The planes are in the space the matrix transforms from, e.g. world space for a view-projection matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_matrix_vk(view_projection: Mat4) -> Self {
+        let rows = view_projection.transposed().cols;
+        Self::from_clip_rows(rows, rows[2], rows[3] - rows[2])
+    }
+
+    /// Extract the frustum from a view-projection (or just a projection) matrix whose clip space depth
+    /// extends from 0.0 (near) to 1.0 (far), such as the `projection::*_dx` matrices.
+    ///
+    /// This is the same as `from_matrix_vk`, as the depth conventions only differ in the direction of y.
+    #[inline]
+    #[must_use]
+    pub fn from_matrix_dx(view_projection: Mat4) -> Self {
+        Self::from_matrix_vk(view_projection)
+    }
+
+    /// Extract the frustum from a view-projection (or just a projection) matrix with reversed Z, i.e. whose
+    /// clip space depth extends from 1.0 (near) to 0.0 (far), such as the `projection::perspective_reversed_*`
+    /// matrices.
+    ///
+    /// The planes are in the space the matrix transforms from, e.g. world space for a view-projection matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_matrix_reversed_z(view_projection: Mat4) -> Self {
+        let rows = view_projection.transposed().cols;
+        Self::from_clip_rows(rows, rows[3] - rows[2], rows[2])
+    }
+
+    fn from_clip_rows(rows: [Vec4; 4], near: Vec4, far: Vec4) -> Self {
+        let plane = |coeffs: Vec4| {
+            let normal = coeffs.xyz();
+            let mag = normal.mag();
+            if mag > 0.0 {
+                Plane::new(normal / mag, -coeffs.w / mag)
+            } else {
+                Plane::new(Vec3::zero(), f32::NEG_INFINITY)
+            }
+        };
+        Self {
+            left: plane(rows[3] + rows[0]),
+            right: plane(rows[3] - rows[0]),
+            bottom: plane(rows[3] + rows[1]),
+            top: plane(rows[3] - rows[1]),
+            near: plane(near),
+            far: plane(far),
+        }
+    }
+
+    /// All six planes, in the order left, right, bottom, top, near, far.
+    #[inline]
+    #[must_use]
+    pub fn planes(&self) -> [Plane; 6] {
+        [self.left, self.right, self.bottom, self.top, self.near, self.far]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes().iter().all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Whether the sphere is at least partially inside this frustum.
+    ///
+    /// This test is conservative, i.e. some spheres outside but close to the corners
+    /// and edges of the frustum are also reported as intersecting.
+    #[inline]
+    #[must_use]
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes().iter().all(|plane| plane.signed_distance(sphere.center) >= -sphere.radius)
+    }
+
+    /// Whether the sphere is completely inside this frustum.
+    #[inline]
+    #[must_use]
+    pub fn contains_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes().iter().all(|plane| plane.signed_distance(sphere.center) >= sphere.radius)
+    }
+
+    /// Whether the box is at least partially inside this frustum.
+    ///
+    /// This test is conservative, i.e. some boxes outside but close to the corners
+    /// and edges of the frustum are also reported as intersecting.
+    #[inline]
+    #[must_use]
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        let (center, extents) = (aabb.center(), aabb.half_extents());
+        self.planes()
+            .iter()
+            .all(|plane| plane.signed_distance(center) >= -plane.normal.abs().dot(extents))
+    }
+
+    /// Whether the box is completely inside this frustum.
+    #[inline]
+    #[must_use]
+    pub fn contains_aabb(&self, aabb: &Aabb) -> bool {
+        let (center, extents) = (aabb.center(), aabb.half_extents());
+        self.planes()
+            .iter()
+            .all(|plane| plane.signed_distance(center) >= plane.normal.abs().dot(extents))
+    }
+}
+
+/// Four axis-aligned bounding boxes, one in each lane.
+#[derive(Default, Debug, Copy, Clone)]
+#[repr(C)]
+pub struct WAabb {
+    pub min: Wec3,
+    pub max: Wec3,
+}
+
+impl WAabb {
+    #[inline]
+    #[must_use]
+    pub fn new(min: Wec3, max: Wec3) -> Self {
+        Self { min, max }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> Wec3 {
+        (self.min + self.max) * f32x4::from(0.5)
+    }
+
+    /// Half of the size of each box along each axis, i.e. the distance from the center to the faces.
+    #[inline]
+    #[must_use]
+    pub fn half_extents(&self) -> Wec3 {
+        (self.max - self.min) * f32x4::from(0.5)
+    }
+}
+
+/// Four spheres, one in each lane.
+#[derive(Default, Debug, Copy, Clone)]
+#[repr(C)]
+pub struct WSphere {
+    pub center: Wec3,
+    pub radius: f32x4,
+}
+
+impl WSphere {
+    #[inline]
+    #[must_use]
+    pub fn new(center: Wec3, radius: f32x4) -> Self {
+        Self { center, radius }
+    }
+}
+
+/// Four rays, one in each lane.
+#[derive(Default, Debug, Copy, Clone)]
+#[repr(C)]
+pub struct WRay {
+    pub origin: Wec3,
+
+    /// normalized direction vectors of the rays
+    pub direction: Wec3,
+}
+
+impl WRay {
+    #[inline]
+    pub fn new(origin: Wec3, direction: Wec3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the points along the rays at distances `t` from their origins.
+    #[inline]
+    pub fn at_distance(&self, t: f32x4) -> Wec3 {
+        self.direction.mul_add(Wec3::broadcast(t), self.origin)
+    }
+
+    /// Intersect these four rays with one box, e.g. to trace a packet of coherent rays through a BVH.
+    ///
+    /// Returns a lane mask which is set for the rays that hit the box, and the entry and exit
+    /// distances as in `Ray::intersect_aabb`, which are only meaningful in the lanes that hit.
+    #[inline]
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> (f32x4, f32x4, f32x4) {
+        let inv_dir = Wec3::one() / self.direction;
+        slab_test(self.origin, inv_dir, Wec3::splat(aabb.min), Wec3::splat(aabb.max))
+    }
+}
+
+/// The entry and exit distances of a ray through the slab between `min` and `max` along one axis.
+///
+/// A ray parallel to the slab either stays inside it, which doesn't constrain the distances, or
+/// never enters it. This is checked explicitly, since lying on one of the slab's planes would
+/// otherwise compute `0 * inf = NaN`.
+#[inline]
+fn slab(origin: f32, inv_dir: f32, min: f32, max: f32) -> (f32, f32) {
+    if inv_dir.is_infinite() {
+        if min <= origin && origin <= max {
+            (f32::NEG_INFINITY, f32::INFINITY)
+        } else {
+            (f32::INFINITY, f32::NEG_INFINITY)
+        }
+    } else {
+        let (t0, t1) = ((min - origin) * inv_dir, (max - origin) * inv_dir);
+        (t0.min(t1), t0.max(t1))
+    }
+}
+
+/// The four-wide version of `slab`.
+#[inline]
+fn wide_slab(origin: f32x4, inv_dir: f32x4, min: f32x4, max: f32x4) -> (f32x4, f32x4) {
+    let inf = f32x4::from(f32::INFINITY);
+    let (t0, t1) = ((min - origin) * inv_dir, (max - origin) * inv_dir);
+    let parallel = inv_dir.abs().cmp_eq(inf);
+    let inside = origin.cmp_ge(min) & origin.cmp_le(max);
+    (
+        parallel.blend(inside.blend(-inf, inf), t0.min(t1)),
+        parallel.blend(inside.blend(inf, -inf), t0.max(t1)),
+    )
+}
+
+/// The slab test of rays against boxes, returning the hit mask and the entry and exit distances.
+#[inline]
+fn slab_test(origin: Wec3, inv_dir: Wec3, min: Wec3, max: Wec3) -> (f32x4, f32x4, f32x4) {
+    let (x0, x1) = wide_slab(origin.x, inv_dir.x, min.x, max.x);
+    let (y0, y1) = wide_slab(origin.y, inv_dir.y, min.y, max.y);
+    let (z0, z1) = wide_slab(origin.z, inv_dir.z, min.z, max.z);
+    let entry = x0.max(y0).max(z0);
+    let exit = x1.min(y1).min(z1);
+    (exit.cmp_ge(entry.max(f32x4::from(0.0))), entry, exit)
+}
+
+impl Frustum {
+    /// Cull four spheres at once.
+    ///
+    /// Returns two lane masks: the first one is set for the spheres which are at least partially inside this
+    /// frustum (i.e. visible), and the second one for the spheres which are completely inside it, which
+    /// is a subset of the first one. See `intersects_sphere` and `contains_sphere`.
+    #[inline]
+    #[must_use]
+    pub fn cull_spheres(&self, spheres: &WSphere) -> (f32x4, f32x4) {
+        self.cull(spheres.center, |_| spheres.radius)
+    }
+
+    /// Cull four boxes at once.
+    ///
+    /// Returns two lane masks: the first one is set for the boxes which are at least partially inside this
+    /// frustum (i.e. visible), and the second one for the boxes which are completely inside it, which
+    /// is a subset of the first one. See `intersects_aabb` and `contains_aabb`.
+    #[inline]
+    #[must_use]
+    pub fn cull_aabbs(&self, aabbs: &WAabb) -> (f32x4, f32x4) {
+        let extents = aabbs.half_extents();
+        self.cull(aabbs.center(), |normal| Wec3::splat(normal.abs()).dot(extents))
+    }
+
+    /// Compare the signed distance of the `centers` to each plane against the `radius` of the
+    /// bounding volumes projected onto the plane normal.
+    #[inline]
+    fn cull(&self, centers: Wec3, radius: impl Fn(Vec3) -> f32x4) -> (f32x4, f32x4) {
+        let test = |plane: &Plane| {
+            let distance = Wec3::splat(plane.normal).dot(centers) - f32x4::from(plane.bias);
+            let radius = radius(plane.normal);
+            (distance.cmp_ge(-radius), distance.cmp_ge(radius))
+        };
+        let planes = self.planes();
+        let (mut visible, mut inside) = test(&planes[0]);
+        for plane in planes[1..].iter() {
+            let (plane_visible, plane_inside) = test(plane);
+            visible &= plane_visible;
+            inside &= plane_inside;
+        }
+        (visible, inside)
+    }
+}
+
+/// A triangle, e.g. of a mesh, whose front face is the one where the vertices `a`, `b`, `c` appear counter-clockwise.
+#[derive(Default, Debug, Copy, Clone)]
+#[repr(C)]
+pub struct Triangle {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3,
+}
+
+impl Triangle {
+    #[inline]
+    #[must_use]
+    pub fn new(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self { a, b, c }
+    }
+
+    /// The normalized normal of the front face of this triangle.
+    #[inline]
+    #[must_use]
+    pub fn normal(&self) -> Vec3 {
+        (self.b - self.a).cross(self.c - self.a).normalized()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        (self.b - self.a).cross(self.c - self.a).mag() * 0.5
+    }
+
+    /// The point with the provided barycentric coordinates, i.e. the weights of `a`, `b` and `c` respectively.
+    #[inline]
+    #[must_use]
+    pub fn barycentric_to_point(&self, barycentric: Vec3) -> Vec3 {
+        self.a * barycentric.x + self.b * barycentric.y + self.c * barycentric.z
+    }
+
+    /// The barycentric coordinates of the provided point (projected onto the plane of this triangle),
+    /// i.e. the weights of `a`, `b` and `c` respectively. They sum to 1, and are all
+    /// non-negative if the point is inside the triangle.
+    ///
+    /// A degenerate triangle (one whose vertices are collinear, i.e. whose `area` is zero) has no
+    /// unique barycentric coordinates, so all of the returned coordinates are NaN.
+    #[inline]
+    #[must_use]
+    pub fn barycentric(&self, point: Vec3) -> Vec3 {
+        let (ab, ac, ap) = (self.b - self.a, self.c - self.a, point - self.a);
+        let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+        let (d20, d21) = (ap.dot(ab), ap.dot(ac));
+        let inv_denom = 1.0 / (d00 * d11 - d01 * d01);
+        let v = (d11 * d20 - d01 * d21) * inv_denom;
+        let w = (d00 * d21 - d01 * d20) * inv_denom;
+        Vec3::new(1.0 - v - w, v, w)
+    }
+
+    /// The point on (or inside) this triangle which is closest to the provided point.
+    #[must_use]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let (ab, ac, ap) = (b - a, c - a, point - a);
+
+        // check whether the point is closest to one of the vertices or edges,
+        // by checking which of their voronoi regions it is in
+        let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+
+        let bp = point - b;
+        let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        // otherwise the point is inside the triangle's prism, so project it onto the face
+        let denom = 1.0 / (va + vb + vc);
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+
+    /// Returns the distance along the ray at which it intersects this triangle, and the barycentric
+    /// coordinates of the intersection (see `barycentric`), or `None` if it misses.
+    ///
+    /// Both faces of the triangle are hit. This uses the Möller–Trumbore algorithm.
+    #[inline]
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<(f32, Vec3)> {
+        let (ab, ac) = (self.b - self.a, self.c - self.a);
+        let p = ray.direction.cross(ac);
+        let det = ab.dot(p);
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let s = ray.origin - self.a;
+        let u = s.dot(p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = s.cross(ab);
+        let v = ray.direction.dot(q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = ac.dot(q) * inv_det;
+        if t < 0.0 {
+            return None;
+        }
+        Some((t, Vec3::new(1.0 - u - v, u, v)))
+    }
+
+    /// Intersect this triangle with four rays at once.
+    ///
+    /// Returns a lane mask which is set for the rays that hit the triangle, and the distances and
+    /// barycentric coordinates as in `intersect_ray`, which are only meaningful in the lanes that hit.
+    #[inline]
+    pub fn intersect_rays(&self, rays: &WRay) -> (f32x4, f32x4, Wec3) {
+        moller_trumbore(Wec3::splat(self.a), Wec3::splat(self.b), Wec3::splat(self.c), rays.origin, rays.direction)
+    }
+}
+
+/// Four triangles, one in each lane.
+#[derive(Default, Debug, Copy, Clone)]
+#[repr(C)]
+pub struct WTriangle {
+    pub a: Wec3,
+    pub b: Wec3,
+    pub c: Wec3,
+}
+
+impl WTriangle {
+    #[inline]
+    #[must_use]
+    pub fn new(a: Wec3, b: Wec3, c: Wec3) -> Self {
+        Self { a, b, c }
+    }
+
+    /// The normalized normals of the front faces of these triangles.
+    #[inline]
+    #[must_use]
+    pub fn normal(&self) -> Wec3 {
+        (self.b - self.a).cross(self.c - self.a).normalized()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> f32x4 {
+        (self.b - self.a).cross(self.c - self.a).mag() * f32x4::from(0.5)
+    }
+
+    /// The points with the provided barycentric coordinates, see `Triangle::barycentric_to_point`.
+    #[inline]
+    #[must_use]
+    pub fn barycentric_to_point(&self, barycentric: Wec3) -> Wec3 {
+        self.a * barycentric.x + self.b * barycentric.y + self.c * barycentric.z
+    }
+
+    /// Intersect these four triangles with one ray.
+    ///
+    /// Returns a lane mask which is set for the triangles that are hit, and the distances and
+    /// barycentric coordinates as in `Triangle::intersect_ray`, which are only meaningful in the lanes
+    /// that are hit.
+    #[inline]
+    pub fn intersect_ray(&self, ray: &Ray) -> (f32x4, f32x4, Wec3) {
+        moller_trumbore(self.a, self.b, self.c, Wec3::splat(ray.origin), Wec3::splat(ray.direction))
+    }
+}
+
+/// The Möller–Trumbore test of rays against triangles, returning the hit mask, distances and barycentric coordinates.
+#[inline]
+fn moller_trumbore(a: Wec3, b: Wec3, c: Wec3, origin: Wec3, direction: Wec3) -> (f32x4, f32x4, Wec3) {
+    let zero = f32x4::from(0.0);
+    let one = f32x4::from(1.0);
+    let (ab, ac) = (b - a, c - a);
+    let p = direction.cross(ac);
+    let det = ab.dot(p);
+    let inv_det = one / det;
+    let s = origin - a;
+    let u = s.dot(p) * inv_det;
+    let q = s.cross(ab);
+    let v = direction.dot(q) * inv_det;
+    let t = ac.dot(q) * inv_det;
+    let hit = det.cmp_ne(zero)
+        & u.cmp_ge(zero)
+        & v.cmp_ge(zero)
+        & (u + v).cmp_le(one)
+        & t.cmp_ge(zero);
+    (hit, t, Wec3::new(one - u - v, u, v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::projection::rh_yup::*;
+    use crate::util::EqualsEps;
+
+    /// `forward` is the sign of z in front of the camera: -1.0 when right-handed, 1.0 when left-handed.
+    fn check_frustum(frustum: Frustum, forward: f32, far: Option<f32>) {
+        let at = |x: f32, y: f32, depth: f32| Vec3::new(x, y, forward * depth);
+        let aabb = |min: Vec3, max: Vec3| {
+            let (a, b) = (at(min.x, min.y, min.z), at(max.x, max.y, max.z));
+            Aabb::new(a.min_by_component(b), a.max_by_component(b))
+        };
+
+        // near is 1.0, the half-angle is 45 degrees and the aspect ratio is 1
+        assert!(frustum.contains_point(at(0.0, 0.0, 2.0)));
+        assert!(frustum.contains_point(at(1.9, -1.9, 2.0)));
+        assert!(!frustum.contains_point(at(2.1, 0.0, 2.0)));
+        assert!(!frustum.contains_point(at(0.0, 0.0, 0.9)));
+        assert!(!frustum.contains_point(at(0.0, 0.0, -2.0)));
+        assert!(frustum.near.signed_distance(at(0.0, 0.0, 3.0)).eq_eps(2.0));
+        match far {
+            Some(far) => {
+                assert!(frustum.contains_point(at(0.0, 0.0, far - 0.1)));
+                assert!(!frustum.contains_point(at(0.0, 0.0, far + 0.1)));
+            }
+            None => assert!(frustum.contains_point(at(0.0, 0.0, 1.0e6))),
+        }
+
+        let sphere = Sphere::new(at(4.5, 0.0, 4.0), 1.0);
+        assert!(frustum.intersects_sphere(&sphere) && !frustum.contains_sphere(&sphere));
+        assert!(frustum.contains_sphere(&Sphere::new(at(0.0, 0.0, 4.0), 1.0)));
+        assert!(!frustum.intersects_sphere(&Sphere::new(at(0.0, 0.0, -2.0), 1.0)));
+
+        let straddling = aabb(Vec3::new(3.5, -0.5, 3.5), Vec3::new(4.5, 0.5, 4.5));
+        assert!(frustum.intersects_aabb(&straddling) && !frustum.contains_aabb(&straddling));
+        assert!(frustum.contains_aabb(&aabb(Vec3::new(-0.5, -0.5, 3.5), Vec3::new(0.5, 0.5, 4.5))));
+        assert!(!frustum.intersects_aabb(&aabb(Vec3::new(5.0, -0.5, 3.5), Vec3::new(6.0, 0.5, 4.5))));
+    }
+
+    #[test]
+    fn frustum_from_projections() {
+        let fov = std::f32::consts::FRAC_PI_2;
+        check_frustum(Frustum::from_matrix_gl(perspective_gl(fov, 1.0, 1.0, 10.0)), -1.0, Some(10.0));
+        check_frustum(Frustum::from_matrix_vk(perspective_vk(fov, 1.0, 1.0, 10.0)), -1.0, Some(10.0));
+        check_frustum(Frustum::from_matrix_dx(perspective_dx(fov, 1.0, 1.0, 10.0)), -1.0, Some(10.0));
+        check_frustum(Frustum::from_matrix_gl(perspective_infinite_z_gl(fov, 1.0, 1.0)), -1.0, None);
+        check_frustum(Frustum::from_matrix_vk(perspective_infinite_z_vk(fov, 1.0, 1.0)), -1.0, None);
+        check_frustum(Frustum::from_matrix_reversed_z(perspective_reversed_z_vk(fov, 1.0, 1.0, 10.0)), -1.0, Some(10.0));
+        check_frustum(Frustum::from_matrix_reversed_z(perspective_reversed_infinite_z_vk(fov, 1.0, 1.0)), -1.0, None);
+
+        macro_rules! check_left_handed {
+            ($($module:ident),+) => {$(
+                use crate::projection::$module;
+                check_frustum(Frustum::from_matrix_gl($module::perspective_gl(fov, 1.0, 1.0, 10.0)), 1.0, Some(10.0));
+                check_frustum(Frustum::from_matrix_vk($module::perspective_vk(fov, 1.0, 1.0, 10.0)), 1.0, Some(10.0));
+                check_frustum(Frustum::from_matrix_dx($module::perspective_dx(fov, 1.0, 1.0, 10.0)), 1.0, Some(10.0));
+                check_frustum(Frustum::from_matrix_gl($module::perspective_infinite_z_gl(fov, 1.0, 1.0)), 1.0, None);
+                check_frustum(Frustum::from_matrix_vk($module::perspective_infinite_z_vk(fov, 1.0, 1.0)), 1.0, None);
+                check_frustum(Frustum::from_matrix_dx($module::perspective_infinite_z_dx(fov, 1.0, 1.0)), 1.0, None);
+                check_frustum(
+                    Frustum::from_matrix_reversed_z($module::perspective_reversed_z_dx_gl(fov, 1.0, 1.0, 10.0)),
+                    1.0,
+                    Some(10.0),
+                );
+                check_frustum(
+                    Frustum::from_matrix_reversed_z($module::perspective_reversed_z_vk(fov, 1.0, 1.0, 10.0)),
+                    1.0,
+                    Some(10.0),
+                );
+                check_frustum(
+                    Frustum::from_matrix_reversed_z($module::perspective_reversed_infinite_z_dx_gl(fov, 1.0, 1.0)),
+                    1.0,
+                    None,
+                );
+                check_frustum(
+                    Frustum::from_matrix_reversed_z($module::perspective_reversed_infinite_z_vk(fov, 1.0, 1.0)),
+                    1.0,
+                    None,
+                );
+            )+};
+        }
+        check_left_handed!(lh_yup, lh_ydown);
+    }
+
+    #[test]
+    fn frustum_view_projection() {
+        let view = Mat4::look_at(Vec3::new(5.0, 0.0, 0.0), Vec3::new(5.0, 0.0, -1.0), Vec3::unit_y());
+        let frustum = Frustum::from_matrix_gl(perspective_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0) * view);
+        assert!(frustum.contains_point(Vec3::new(5.0, 0.0, -2.0)));
+        assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -2.0)));
+    }
+
+    #[test]
+    fn ray_aabb() {
+        use crate::Lanes;
+
+        let aabb = Aabb::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+        let ray = Ray { origin: Vec3::new(-3.0, 0.5, 0.0), direction: Vec3::unit_x() };
+        let (entry, exit) = ray.intersect_aabb(&aabb).unwrap();
+        assert!(entry.eq_eps(2.0) && exit.eq_eps(4.0));
+        let inside = Ray { origin: Vec3::zero(), direction: Vec3::unit_y() };
+        assert_eq!(inside.intersect_aabb(&aabb), Some((-1.0, 1.0)));
+        let behind = Ray { origin: Vec3::new(3.0, 0.0, 0.0), direction: Vec3::unit_x() };
+        assert!(behind.intersect_aabb(&aabb).is_none());
+        let miss = Ray { origin: Vec3::new(-3.0, 1.5, 0.0), direction: Vec3::unit_x() };
+        assert!(miss.intersect_aabb(&aabb).is_none());
+
+        let offset = |x: f32| Aabb::new(aabb.min + Vec3::new(x, 0.0, 0.0), aabb.max + Vec3::new(x, 0.0, 0.0));
+        let above = Aabb::new(Vec3::new(5.0, 2.0, -1.0), Vec3::new(6.0, 3.0, 1.0));
+        let boxes = [offset(0.0), offset(-10.0), above, offset(5.0)];
+        let (hit, entry, exit) = ray.intersect_aabbs(&WAabb::from_lanes(&boxes));
+        assert_eq!(hit.move_mask(), 0b1001);
+        assert!(entry.extract(3).eq_eps(7.0) && exit.extract(3).eq_eps(9.0));
+
+        let rays = [ray, inside, behind, miss];
+        let (hit, entry, exit) = WRay::from_lanes(&rays).intersect_aabb(&aabb);
+        assert_eq!(hit.move_mask(), 0b0011);
+        assert!(entry.extract(0).eq_eps(2.0) && exit.extract(1).eq_eps(1.0));
+    }
+
+    #[test]
+    fn ray_aabb_on_face() {
+        use crate::Lanes;
+
+        let aabb = Aabb::new(Vec3::zero(), Vec3::one());
+        let rays = [
+            Ray { origin: Vec3::new(1.0, 0.5, -1.0), direction: Vec3::unit_z() },
+            Ray { origin: Vec3::new(0.0, 0.5, -1.0), direction: Vec3::unit_z() },
+            Ray { origin: Vec3::new(0.0, 0.0, -1.0), direction: Vec3::unit_z() },
+            Ray { origin: Vec3::new(1.5, 0.5, -1.0), direction: Vec3::unit_z() },
+        ];
+        assert_eq!(rays[0].intersect_aabb(&aabb), Some((1.0, 2.0)));
+        assert!(rays[3].intersect_aabb(&aabb).is_none());
+
+        let (hit, entry, exit) = WRay::from_lanes(&rays).intersect_aabb(&aabb);
+        assert_eq!(hit.move_mask(), 0b0111);
+        for (i, ray) in rays.iter().enumerate() {
+            let (hits, entries, exits) = ray.intersect_aabbs(&WAabb::from_lanes(&[aabb; 4]));
+            assert_eq!(hits.move_mask(), if i < 3 { 0b1111 } else { 0 });
+            if let Some((scalar_entry, scalar_exit)) = ray.intersect_aabb(&aabb) {
+                assert_eq!((entry.extract(i), exit.extract(i)), (scalar_entry, scalar_exit));
+                assert_eq!((entries.extract(0), exits.extract(0)), (scalar_entry, scalar_exit));
+            }
+        }
+    }
+
+    #[test]
+    fn triangle() {
+        let tri = Triangle::new(Vec3::zero(), Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+        assert!(tri.normal().eq_eps(Vec3::unit_z()));
+        assert!(tri.area().eq_eps(2.0));
+
+        let point = Vec3::new(0.5, 1.0, 0.0);
+        let barycentric = tri.barycentric(point);
+        assert!(barycentric.eq_eps(Vec3::new(0.25, 0.25, 0.5)));
+        assert!(tri.barycentric_to_point(barycentric).eq_eps(point));
+        let degenerate = Triangle::new(Vec3::zero(), Vec3::unit_x(), Vec3::new(2.0, 0.0, 0.0));
+        assert!(degenerate.barycentric(point).x.is_nan());
+
+        assert!(tri.closest_point(Vec3::new(0.5, 0.5, 3.0)).eq_eps(Vec3::new(0.5, 0.5, 0.0)));
+        assert!(tri.closest_point(Vec3::new(-1.0, -1.0, 0.0)).eq_eps(Vec3::zero()));
+        assert!(tri.closest_point(Vec3::new(1.0, -1.0, 1.0)).eq_eps(Vec3::new(1.0, 0.0, 0.0)));
+        assert!(tri.closest_point(Vec3::new(2.0, 2.0, 0.0)).eq_eps(Vec3::new(1.0, 1.0, 0.0)));
+        assert!(tri.closest_point(Vec3::new(3.0, 0.0, 0.0)).eq_eps(Vec3::new(2.0, 0.0, 0.0)));
+
+        let ray = Ray { origin: Vec3::new(0.5, 1.0, 3.0), direction: -Vec3::unit_z() };
+        let (t, hit_barycentric) = tri.intersect_ray(&ray).unwrap();
+        assert!(t.eq_eps(3.0) && hit_barycentric.eq_eps(barycentric));
+        let back = Ray { origin: Vec3::new(0.5, 1.0, -3.0), direction: Vec3::unit_z() };
+        assert!(back.at_distance(tri.intersect_ray(&back).unwrap().0).eq_eps(point));
+        let away = Ray { origin: Vec3::new(0.5, 1.0, 3.0), direction: Vec3::unit_z() };
+        assert!(tri.intersect_ray(&away).is_none());
+        let miss = Ray { origin: Vec3::new(1.5, 1.5, 3.0), direction: -Vec3::unit_z() };
+        assert!(tri.intersect_ray(&miss).is_none());
+        let parallel = Ray { origin: Vec3::new(-1.0, 0.5, 0.0), direction: Vec3::unit_x() };
+        assert!(tri.intersect_ray(&parallel).is_none());
+    }
+
+    #[test]
+    fn wide_triangle() {
+        use crate::Lanes;
+
+        let tri = Triangle::new(Vec3::zero(), Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+        let ray = Ray { origin: Vec3::new(0.5, 1.0, 3.0), direction: -Vec3::unit_z() };
+        let rays = [
+            ray,
+            Ray { origin: Vec3::new(0.5, 1.0, 3.0), direction: Vec3::unit_z() },
+            Ray { origin: Vec3::new(1.5, 1.5, 3.0), direction: -Vec3::unit_z() },
+            Ray { origin: Vec3::new(0.1, 0.1, -1.0), direction: Vec3::unit_z() },
+        ];
+        let (hit, t, barycentric) = tri.intersect_rays(&WRay::from_lanes(&rays));
+        assert_eq!(hit.move_mask(), 0b1001);
+        for (i, ray) in rays.iter().enumerate() {
+            if let Some((scalar_t, scalar_barycentric)) = tri.intersect_ray(ray) {
+                assert!(t.extract(i).eq_eps(scalar_t) && barycentric.extract(i).eq_eps(scalar_barycentric));
+            }
+        }
+
+        let offset = |by: Vec3| Triangle::new(tri.a + by, tri.b + by, tri.c + by);
+        let (shifted, away) = (offset(Vec3::unit_z()), offset(Vec3::new(5.0, 0.0, 0.0)));
+        let tris = WTriangle::from_lanes(&[tri, away, shifted, tri]);
+        let (hit, t, _) = tris.intersect_ray(&ray);
+        assert_eq!(hit.move_mask(), 0b1101);
+        assert!(t.extract(2).eq_eps(2.0));
+        assert!(tris.area().extract(0).eq_eps(tri.area()) && tris.normal().extract(1).eq_eps(tri.normal()));
+    }
+
+    #[test]
+    fn small_triangle() {
+        use crate::Lanes;
+
+        let tri = Triangle::new(Vec3::zero(), Vec3::new(1.0e-4, 0.0, 0.0), Vec3::new(0.0, 1.0e-4, 0.0));
+        let ray = Ray { origin: Vec3::new(2.5e-5, 5.0e-5, 3.0), direction: -Vec3::unit_z() };
+        let (t, barycentric) = tri.intersect_ray(&ray).unwrap();
+        assert!(t.eq_eps(3.0) && barycentric.eq_eps(Vec3::new(0.25, 0.25, 0.5)));
+        let miss = Ray { origin: Vec3::new(1.0e-4, 1.0e-4, 3.0), direction: -Vec3::unit_z() };
+        assert!(tri.intersect_ray(&miss).is_none());
+
+        let (hit, t, _) = tri.intersect_rays(&WRay::from_lanes(&[ray, miss, ray, miss]));
+        assert_eq!(hit.move_mask(), 0b0101);
+        assert!(t.extract(2).eq_eps(3.0));
+        let (hit, _, _) = WTriangle::from_lanes(&[tri; 4]).intersect_ray(&ray);
+        assert_eq!(hit.move_mask(), 0b1111);
+    }
+
+    #[test]
+    fn wide_frustum_culling() {
+        use crate::Lanes;
+
+        let frustum = Frustum::from_matrix_vk(perspective_vk(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0));
+        let spheres = [
+            Sphere::new(Vec3::new(0.0, 0.0, -4.0), 1.0),
+            Sphere::new(Vec3::new(4.5, 0.0, -4.0), 1.0),
+            Sphere::new(Vec3::new(0.0, 0.0, 2.0), 1.0),
+            Sphere::new(Vec3::new(0.0, 0.0, -10.5), 1.0),
+        ];
+        let (visible, inside) = frustum.cull_spheres(&WSphere::from_lanes(&spheres));
+        assert_eq!(visible.move_mask(), 0b1011);
+        assert_eq!(inside.move_mask(), 0b0001);
+        for (i, sphere) in spheres.iter().enumerate() {
+            assert_eq!(visible.move_mask() & (1 << i) != 0, frustum.intersects_sphere(sphere));
+            assert_eq!(inside.move_mask() & (1 << i) != 0, frustum.contains_sphere(sphere));
+        }
+
+        let aabbs = [
+            Aabb::new(Vec3::new(3.5, -0.5, -4.5), Vec3::new(4.5, 0.5, -3.5)),
+            Aabb::new(Vec3::new(-0.5, -0.5, -4.5), Vec3::new(0.5, 0.5, -3.5)),
+            Aabb::new(Vec3::new(5.0, -0.5, -4.5), Vec3::new(6.0, 0.5, -3.5)),
+            Aabb::new(Vec3::new(-1.0, -1.0, -20.0), Vec3::new(1.0, 1.0, -5.0)),
+        ];
+        let (visible, inside) = frustum.cull_aabbs(&WAabb::from_lanes(&aabbs));
+        assert_eq!(visible.move_mask(), 0b1011);
+        assert_eq!(inside.move_mask(), 0b0010);
+        for (i, aabb) in aabbs.iter().enumerate() {
+            assert_eq!(visible.move_mask() & (1 << i) != 0, frustum.intersects_aabb(aabb));
+            assert_eq!(inside.move_mask() & (1 << i) != 0, frustum.contains_aabb(aabb));
+        }
+    }
+}
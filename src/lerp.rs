@@ -1,5 +1,5 @@
-//! Linear interpolation on types for which it makes sense.
-use wide::f32x4;
+//! Linear interpolation on types for which it makes sense, and spherical interpolation for rotors.
+use wide::{f32x4, f32x8, f64x4};
 
 use crate::bivec::*;
 use crate::rotor::*;
@@ -21,5 +21,147 @@ macro_rules! impl_lerp {
 }
 
 impl_lerp!(
-    f32 => (Vec2, Vec3, Vec4, Bivec2, Bivec3, Rotor2, Rotor3),
-    f32x4 => (Wec2, Wec3, Wec4, WBivec2, WBivec3, WRotor2, WRotor3));
+    f32 => (Vec2, Vec3, Vec4, Bivec2, Bivec3, Bivec4, Rotor2, Rotor3, Rotor4),
+    f32x4 => (Wec2, Wec3, Wec4, WBivec2, WBivec3, WBivec4, WRotor2, WRotor3, WRotor4),
+    f32x8 => (Wec2x8, Wec3x8, Wec4x8, WBivec2x8, WBivec3x8, WBivec4x8, WRotor2x8, WRotor3x8, WRotor4x8),
+    f64 => (DVec2, DVec3, DVec4, DBivec2, DBivec3, DBivec4, DRotor2, DRotor3, DRotor4),
+    f64x4 => (DWec2, DWec3, DWec4, DWBivec2, DWBivec3, DWBivec4, DWRotor2, DWRotor3, DWRotor4));
+
+/// Interpolation between rotors which stays on the unit sphere.
+///
+/// Both methods take the shortest path between the two rotations: since `r` and `-r` represent the
+/// same rotation, `end` is negated when its dot product with `self` is negative. Both inputs are
+/// expected to be normalized, and the result is normalized.
+pub trait Slerp<T> {
+    /// Spherical linear interpolation, which moves at a constant angular velocity as `t` goes from `0` to `1`.
+    fn slerp(&self, end: Self, t: T) -> Self;
+
+    /// Normalized linear interpolation. This is cheaper than `slerp` and follows the same path,
+    /// but does not move at a constant angular velocity.
+    fn nlerp(&self, end: Self, t: T) -> Self;
+}
+
+macro_rules! impl_slerp {
+    ($($t:ident => ($($rt:ident),+)),+) => {
+        $($(impl Slerp<$t> for $rt {
+            #[inline]
+            fn slerp(&self, end: Self, t: $t) -> Self {
+                let mut end = end;
+                let mut dot = self.dot(end);
+                if dot < 0.0 {
+                    end *= -1.0;
+                    dot = -dot;
+                }
+
+                // the rotors are almost parallel, where the sin(theta) below is unstable
+                if dot > 0.9995 {
+                    return (*self * (1.0 - t) + end * t).normalized();
+                }
+
+                let theta = dot.acos();
+                let sin_theta = theta.sin();
+                let a = ((1.0 - t) * theta).sin() / sin_theta;
+                let b = (t * theta).sin() / sin_theta;
+                (*self * a + end * b).normalized()
+            }
+
+            #[inline]
+            fn nlerp(&self, end: Self, t: $t) -> Self {
+                let end = if self.dot(end) < 0.0 { end * -1.0 } else { end };
+                (*self * (1.0 - t) + end * t).normalized()
+            }
+        })+)+
+    };
+}
+
+impl_slerp!(
+    f32 => (Rotor2, Rotor3),
+    f64 => (DRotor2, DRotor3));
+
+macro_rules! impl_wide_slerp {
+    ($($tt:ident => ($($rt:ident),+)),+) => {
+        $($(impl Slerp<$tt> for $rt {
+            #[inline]
+            fn slerp(&self, end: Self, t: $tt) -> Self {
+                let one = $tt::from(1.0);
+                let dot = self.dot(end);
+                let end = end * dot.cmp_lt($tt::from(0.0)).blend($tt::from(-1.0), one);
+                let dot = dot.abs().min(one);
+
+                let theta = dot.acos();
+                let sin_theta = theta.sin();
+                let a = ((one - t) * theta).sin() / sin_theta;
+                let b = (t * theta).sin() / sin_theta;
+
+                // fall back to nlerp in lanes where the rotors are almost parallel
+                let parallel = dot.cmp_gt($tt::from(0.9995));
+                let a = parallel.blend(one - t, a);
+                let b = parallel.blend(t, b);
+                (*self * a + end * b).normalized()
+            }
+
+            #[inline]
+            fn nlerp(&self, end: Self, t: $tt) -> Self {
+                let one = $tt::from(1.0);
+                let end = end * self.dot(end).cmp_lt($tt::from(0.0)).blend($tt::from(-1.0), one);
+                (*self * (one - t) + end * t).normalized()
+            }
+        })+)+
+    };
+}
+
+impl_wide_slerp!(
+    f32x4 => (WRotor2, WRotor3),
+    f32x8 => (WRotor2x8, WRotor3x8),
+    f64x4 => (DWRotor2, DWRotor3));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+    use crate::Lanes;
+
+    #[test]
+    fn slerp_halfway() {
+        let start = Rotor3::identity();
+        let end = Rotor3::from_rotation_xy(std::f32::consts::FRAC_PI_2);
+        let expected = Rotor3::from_rotation_xy(std::f32::consts::FRAC_PI_4);
+        assert!(start.slerp(end, 0.5).eq_eps(expected));
+        assert!(start.nlerp(end, 0.5).eq_eps(expected));
+        assert!(start.slerp(end, 0.0).eq_eps(start));
+        assert!(start.slerp(end, 1.0).eq_eps(end));
+
+        // -end is the same rotation, so the shortest path should give the same result
+        assert!(start.slerp(end * -1.0, 0.5).eq_eps(expected));
+        assert!(start.nlerp(end * -1.0, 0.5).eq_eps(expected));
+
+        let a = Rotor2::from_angle(0.2);
+        let b = Rotor2::from_angle(1.4);
+        assert!(a.slerp(b, 0.25).eq_eps(Rotor2::from_angle(0.5)));
+    }
+
+    #[test]
+    fn wide_slerp_matches_scalar() {
+        let starts = [
+            Rotor3::identity(),
+            Rotor3::from_euler_angles(0.3, -1.2, 2.5),
+            Rotor3::from_rotation_xz(1.0),
+            Rotor3::from_rotation_yz(0.5),
+        ];
+        let ends = [
+            Rotor3::from_rotation_xy(1.5),
+            Rotor3::from_euler_angles(-2.0, 0.4, 0.1) * -1.0,
+            Rotor3::from_rotation_xz(1.0),
+            Rotor3::from_rotation_yz(0.5 + 1e-4),
+        ];
+        let t = [0.3, 0.7, 0.5, 0.9];
+        let wide_start = WRotor3::from_lanes(&starts);
+        let wide_end = WRotor3::from_lanes(&ends);
+        let slerped = wide_start.slerp(wide_end, f32x4::from(t));
+        let nlerped = wide_start.nlerp(wide_end, f32x4::from(t));
+        for i in 0..4 {
+            assert!(slerped.extract(i).eq_eps(starts[i].slerp(ends[i], t[i])));
+            assert!(nlerped.extract(i).eq_eps(starts[i].nlerp(ends[i], t[i])));
+        }
+    }
+}
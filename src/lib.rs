@@ -14,6 +14,16 @@
 //! each value. This design is clear and explicit in intent, and it also allows code to
 //! take full advantage of SIMD.
 //!
+//! All of the scalar types also come in a double-precision flavor, prefixed with a `D` (`DVec3`, `DMat4`,
+//! `DRotor3`, `DIsometry3`, etc.), for use cases such as large worlds where `f32` precision is not enough.
+//! Single-precision types can be losslessly widened into their double-precision counterparts with `From`,
+//! and double-precision types can be narrowed with `into_f32`. Narrowing is lossy: every component is rounded
+//! to the nearest `f32`, so for example a narrowed rotor may need to be renormalized. The wide types also have
+//! `DW`-prefixed double-precision counterparts (`DWec3`, `DWat4`, `DWRotor3`, etc.) which use `f64x4` lanes.
+//!
+//! The `f32x4` wide types also have 8-lane counterparts suffixed with `x8` (`Wec3x8`, `Wat4x8`, `WRotor3x8`,
+//! `WIsometry3x8`, etc.) which use `f32x8` lanes, so that the full width of an AVX register can be used.
+//!
 //! The 'wide' types use an "SoA" (Structure of Arrays) architecture
 //! such that each `Wec` (wide-Vec) actually contains the data for 4 `Vec`s and will do any operation
 //! on all 4 of the vector 'lanes' at the same time (the same concept applies to a `Wat`, or 'wide-Mat').
@@ -62,32 +72,44 @@
 //! understand them. Second is that in some sense they can be seen as 'more correct' than Quaternions, and especially they
 //! facilitate a more proper understanding of rotation as being something that occurs *within a plane* rather than something
 //! that occurs *around an axis*, as it is generally thought. Finally, Rotors also generalize do 4 and even higher dimensions,
-//! and if someone wants to they could implement a Rotor4 which retains all the properties of a Rotor3/Quaternion but does rotation
+//! and `ultraviolet` provides a `Rotor4` (along with `Bivec4`) which retains all the properties of a Rotor3/Quaternion but does rotation
 //! in 4 dimensions instead, something which simply is not possible to do with Quaternions.
 //!
 //! If it's missing something you need it to do, bug me on the [GitHub issue tracker](https://github.com/termhn/ultraviolet/issues) and/or Rust community discord server
 //! (I'm Fusha there) and I'll try to add it for you, if I believe it fits with the vision of the lib :)
 
-mod support;
+#[macro_use]
 mod util;
+mod decompose;
+mod support;
 
+pub mod batch;
 pub mod bivec;
 pub mod geometry;
 pub mod int;
 pub mod lerp;
 pub mod mat;
+pub mod multivec;
+pub mod pga;
 pub mod projection;
 pub mod rotor;
 pub mod transform;
+pub mod trivec;
 pub mod vec;
 
+pub use batch::Lanes;
 pub use bivec::*;
 pub use int::*;
 pub use lerp::*;
 pub use mat::*;
+pub use multivec::*;
+pub use pga::*;
 pub use rotor::*;
 pub use transform::*;
+pub use trivec::*;
 pub use vec::*;
 
 pub use wide;
 pub use wide::f32x4;
+pub use wide::f32x8;
+pub use wide::f64x4;
@@ -1,10 +1,10 @@
-//! Square matrices.
+//! Square matrices, and non-square matrices for compactly storing affine transformations.
 use std::alloc::Layout;
 use std::ops::*;
 
 use crate::*;
 
-use wide::f32x4;
+use wide::{f32x4, f32x8, f64x4};
 
 macro_rules! mat2s {
     ($($n:ident => $m3t:ident, $v3t:ident, $vt:ident, $t:ident),+) => {
@@ -44,6 +44,70 @@ macro_rules! mat2s {
                 )
             }
 
+            #[inline]
+            pub fn from_nonuniform_scale(scale: $vt) -> Self {
+                let zero = $t::from(0.0);
+                Self::new(
+                    $vt::new(scale.x, zero),
+                    $vt::new(zero, scale.y),
+                )
+            }
+
+            /// Builds a 2d rotation matrix from a given angle in radians. A positive angle
+            /// rotates counter-clockwise, i.e. it rotates the x axis towards the y axis.
+            #[inline]
+            pub fn from_rotation(angle: $t) -> Self {
+                let (s, c) = angle.sin_cos();
+                Self::new(
+                    $vt::new(c, s),
+                    $vt::new(-s, c),
+                )
+            }
+
+            #[inline]
+            pub fn determinant(&self) -> $t {
+                self.cols[0].x.mul_add(self.cols[1].y, -(self.cols[1].x * self.cols[0].y))
+            }
+
+            /// The adjugate of this matrix, i.e. the transpose of its cofactor matrix.
+            ///
+            /// This is equal to the inverse multiplied by the determinant, and unlike the
+            /// inverse, it is also defined for matrices which are not invertible.
+            #[inline]
+            pub fn adjugate(&self) -> Self {
+                Self::new(
+                    $vt::new(self.cols[1].y, -self.cols[0].y),
+                    $vt::new(-self.cols[1].x, self.cols[0].x),
+                )
+            }
+
+            /// If this matrix is not currently invertable, this function will return
+            /// an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inverse(&mut self) {
+                *self = self.inversed();
+            }
+
+            /// If this matrix is not currently invertable, this function will return
+            /// an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inversed(&self) -> Self {
+                self.adjugate() * ($t::from(1.0) / self.determinant())
+            }
+
+            #[inline]
+            pub fn transpose(&mut self) {
+                *self = self.transposed();
+            }
+
+            #[inline]
+            pub fn transposed(&self) -> Self {
+                Self::new(
+                    $vt::new(self.cols[0].x, self.cols[1].x),
+                    $vt::new(self.cols[0].y, self.cols[1].y),
+                )
+            }
+
             /// Turn this into a homogeneous 2d transformation matrix.
             #[inline]
             pub fn into_homogeneous(self) -> $m3t {
@@ -137,10 +201,10 @@ macro_rules! mat2s {
                 Self::new(
                     $vt::new(
                         sa.x * oa.x + sb.x * oa.y,
-                        sa.x * ob.x + sb.x * ob.y,
+                        sa.y * oa.x + sb.y * oa.y,
                     ),
                     $vt::new(
-                        sa.y * oa.x + sb.y * oa.y,
+                        sa.x * ob.x + sb.x * ob.y,
                         sa.y * ob.x + sb.y * ob.y,
                     ),
                 )
@@ -160,6 +224,15 @@ macro_rules! mat2s {
             }
         }
 
+        impl Mul<$t> for $n {
+            type Output = Self;
+            #[inline]
+            fn mul(mut self, rhs: $t) -> Self {
+                self.cols.iter_mut().for_each(|c| *c = rhs * *c);
+                self
+            }
+        }
+
         impl From<[$t; 4]> for $n {
             #[inline]
             fn from(comps: [$t; 4]) -> Self {
@@ -174,8 +247,8 @@ macro_rules! mat2s {
             #[inline]
             fn from(comps: [[$t; 2]; 2]) -> Self {
                 Self::new(
-                    $vt::new(comps[0][0], comps[0][0]),
-                    $vt::new(comps[1][1], comps[1][1])
+                    $vt::new(comps[0][0], comps[0][1]),
+                    $vt::new(comps[1][0], comps[1][1])
                 )
             }
         }
@@ -214,7 +287,13 @@ macro_rules! mat2s {
     }
 }
 
-mat2s!(Mat2 => Mat3, Vec3, Vec2, f32, Wat2 => Wat3, Wec3, Wec2, f32x4);
+mat2s!(
+    Mat2 => Mat3, Vec3, Vec2, f32,
+    Wat2 => Wat3, Wec3, Wec2, f32x4,
+    Wat2x8 => Wat3x8, Wec3x8, Wec2x8, f32x8,
+    DMat2 => DMat3, DVec3, DVec2, f64,
+    DWat2 => DWat3, DWec3, DWec2, f64x4
+);
 
 impl PartialEq for Mat2 {
     fn eq(&self, other: &Self) -> bool {
@@ -226,6 +305,12 @@ impl PartialEq for Mat2 {
     }
 }
 
+impl PartialEq for DMat2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cols[0] == other.cols[0] && self.cols[1] == other.cols[1]
+    }
+}
+
 macro_rules! mat3s {
     ($($n:ident => $rt:ident, $bt:ident, $m4t:ident, $v4t:ident, $v2t:ident, $vt:ident, $t:ident),+) => {
         /// A 3x3 square matrix.
@@ -421,7 +506,12 @@ macro_rules! mat3s {
             /// an invalid inverse. This status is not checked by the library.
             #[inline]
             pub fn inverse(&mut self) {
-                *self = self.transposed();
+                *self = self.inversed();
+            }
+
+            #[inline]
+            pub fn determinant(&self) -> $t {
+                self.cols[0].dot(self.cols[1].cross(self.cols[2]))
             }
 
             /// If this matrix is not currently invertable, this function will return
@@ -431,7 +521,7 @@ macro_rules! mat3s {
                 let x = self.cols[1].cross(self.cols[2]);
                 let y = self.cols[2].cross(self.cols[0]);
                 let z = self.cols[0].cross(self.cols[1]);
-                let det = self.cols[2].dot(y);
+                let det = self.cols[0].dot(x);
                 let inv_det = $t::from(1.0) / det;
 
                 Self::new(x * inv_det, y * inv_det, z * inv_det).transposed()
@@ -454,6 +544,77 @@ macro_rules! mat3s {
                 )
             }
 
+            /// The inverse of this matrix, assuming it is a 2d affine transformation, i.e. that its
+            /// last row is `[0, 0, 1]`. This is cheaper than a general inverse.
+            ///
+            /// If this matrix is not currently invertable, this function will return
+            /// an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inverse_affine(&mut self) {
+                *self = self.inversed_affine();
+            }
+
+            /// The inverse of this matrix, assuming it is a 2d affine transformation, i.e. that its
+            /// last row is `[0, 0, 1]`. This is cheaper than a general inverse.
+            ///
+            /// If this matrix is not currently invertable, this function will return
+            /// an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inversed_affine(&self) -> Self {
+                let a = self.cols[0].xy();
+                let b = self.cols[1].xy();
+                let t = self.cols[2].xy();
+                let inv_det = $t::from(1.0) / a.x.mul_add(b.y, -(b.x * a.y));
+                // the rows of the inverse of the linear part
+                let x = $v2t::new(b.y, -b.x) * inv_det;
+                let y = $v2t::new(-a.y, a.x) * inv_det;
+                let zero = $t::from(0.0);
+                Self::new(
+                    $vt::new(x.x, y.x, zero),
+                    $vt::new(x.y, y.y, zero),
+                    $vt::new(-x.dot(t), -y.dot(t), $t::from(1.0)),
+                )
+            }
+
+            /// The inverse of this matrix, assuming it is a 2d rigid body transformation, i.e. a
+            /// rotation followed by a translation. This is cheaper than `inverse_affine`.
+            #[inline]
+            pub fn inverse_orthonormal(&mut self) {
+                *self = self.inversed_orthonormal();
+            }
+
+            /// The inverse of this matrix, assuming it is a 2d rigid body transformation, i.e. a
+            /// rotation followed by a translation. This is cheaper than `inversed_affine`.
+            #[inline]
+            pub fn inversed_orthonormal(&self) -> Self {
+                let a = self.cols[0].xy();
+                let b = self.cols[1].xy();
+                let t = self.cols[2].xy();
+                let zero = $t::from(0.0);
+                Self::new(
+                    $vt::new(a.x, b.x, zero),
+                    $vt::new(a.y, b.y, zero),
+                    $vt::new(-a.dot(t), -b.dot(t), $t::from(1.0)),
+                )
+            }
+
+            /// Transform a 2d point by this matrix, assuming it is a 2d affine transformation.
+            ///
+            /// This is the same as multiplying by the homogeneous point, but skips computing
+            /// (and dividing by) the last component.
+            #[inline]
+            pub fn transform_point2(&self, point: $v2t) -> $v2t {
+                self.cols[0].xy() * point.x + self.cols[1].xy() * point.y + self.cols[2].xy()
+            }
+
+            /// Transform a 2d vector by this matrix, assuming it is a 2d affine transformation.
+            ///
+            /// This is the same as multiplying by the homogeneous vector, i.e. translation is ignored.
+            #[inline]
+            pub fn transform_vec2(&self, vec: $v2t) -> $v2t {
+                self.cols[0].xy() * vec.x + self.cols[1].xy() * vec.y
+            }
+
             #[inline]
             pub fn layout() -> Layout {
                 Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
@@ -652,7 +813,13 @@ macro_rules! mat3s {
     }
 }
 
-mat3s!(Mat3 => Rotor3, Bivec3, Mat4, Vec4, Vec2, Vec3, f32, Wat3 => WRotor3, WBivec3, Wat4, Wec4, Wec2, Wec3, f32x4);
+mat3s!(
+    Mat3 => Rotor3, Bivec3, Mat4, Vec4, Vec2, Vec3, f32,
+    Wat3 => WRotor3, WBivec3, Wat4, Wec4, Wec2, Wec3, f32x4,
+    Wat3x8 => WRotor3x8, WBivec3x8, Wat4x8, Wec4x8, Wec2x8, Wec3x8, f32x8,
+    DMat3 => DRotor3, DBivec3, DMat4, DVec4, DVec2, DVec3, f64,
+    DWat3 => DWRotor3, DWBivec3, DWat4, DWec4, DWec2, DWec3, f64x4
+);
 
 impl PartialEq for Mat3 {
     fn eq(&self, other: &Self) -> bool {
@@ -668,6 +835,14 @@ impl PartialEq for Mat3 {
     }
 }
 
+impl PartialEq for DMat3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cols[0] == other.cols[0]
+            && self.cols[1] == other.cols[1]
+            && self.cols[2] == other.cols[2]
+    }
+}
+
 macro_rules! mat4s {
     ($($n:ident => $rt:ident, $bt:ident, $vt:ident, $v3t:ident, $t:ident),+) => {
         /// A 4x4 square matrix.
@@ -737,6 +912,21 @@ macro_rules! mat4s {
                 )
             }
 
+            /// Create a transformation which applies a non-uniform scale, then a rotation,
+            /// then a translation. This is the inverse of `decompose_trs`.
+            ///
+            /// Assumes homogeneous 3d coordinates.
+            #[inline]
+            pub fn from_trs(translation: $v3t, rotation: $rt, scale: $v3t) -> Self {
+                let rot = rotation.into_matrix();
+                Self::new(
+                    (rot.cols[0] * scale.x).into_homogeneous_vector(),
+                    (rot.cols[1] * scale.y).into_homogeneous_vector(),
+                    (rot.cols[2] * scale.z).into_homogeneous_vector(),
+                    translation.into_homogeneous_point(),
+                )
+            }
+
             /// Angles are applied in the order roll -> pitch -> yaw
             ///
             /// - Roll is rotation inside the xy plane ("around the z axis")
@@ -891,6 +1081,30 @@ macro_rules! mat4s {
                 *self = self.inversed();
             }
 
+            #[inline]
+            pub fn determinant(&self) -> $t {
+                let (m00, m01, m02, m03) = self.cols[0].into();
+                let (m10, m11, m12, m13) = self.cols[1].into();
+                let (m20, m21, m22, m23) = self.cols[2].into();
+                let (m30, m31, m32, m33) = self.cols[3].into();
+
+                let s0 = m00 * m11 - m10 * m01;
+                let s1 = m00 * m12 - m10 * m02;
+                let s2 = m00 * m13 - m10 * m03;
+                let s3 = m01 * m12 - m11 * m02;
+                let s4 = m01 * m13 - m11 * m03;
+                let s5 = m02 * m13 - m12 * m03;
+
+                let c0 = m20 * m31 - m30 * m21;
+                let c1 = m20 * m32 - m30 * m22;
+                let c2 = m20 * m33 - m30 * m23;
+                let c3 = m21 * m32 - m31 * m22;
+                let c4 = m21 * m33 - m31 * m23;
+                let c5 = m22 * m33 - m32 * m23;
+
+                s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+            }
+
             /// If this matrix is not currently invertable, this function will return
             /// an invalid inverse. This status is not checked by the library.
             #[inline]
@@ -967,6 +1181,88 @@ macro_rules! mat4s {
                 inverse * rcp_det
             }
 
+            /// The inverse of this matrix, assuming it is an affine transformation, i.e. that its
+            /// last row is `[0, 0, 0, 1]`. This is much cheaper than a general inverse.
+            ///
+            /// If this matrix is not currently invertable, this function will return
+            /// an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inverse_affine(&mut self) {
+                *self = self.inversed_affine();
+            }
+
+            /// The inverse of this matrix, assuming it is an affine transformation, i.e. that its
+            /// last row is `[0, 0, 0, 1]`. This is much cheaper than a general inverse.
+            ///
+            /// If this matrix is not currently invertable, this function will return
+            /// an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inversed_affine(&self) -> Self {
+                let a = self.cols[0].xyz();
+                let b = self.cols[1].xyz();
+                let c = self.cols[2].xyz();
+                let t = self.cols[3].xyz();
+                let x = b.cross(c);
+                let inv_det = $t::from(1.0) / a.dot(x);
+                // the rows of the inverse of the linear part
+                let x = x * inv_det;
+                let y = c.cross(a) * inv_det;
+                let z = a.cross(b) * inv_det;
+                let zero = $t::from(0.0);
+                Self::new(
+                    $vt::new(x.x, y.x, z.x, zero),
+                    $vt::new(x.y, y.y, z.y, zero),
+                    $vt::new(x.z, y.z, z.z, zero),
+                    $vt::new(-x.dot(t), -y.dot(t), -z.dot(t), $t::from(1.0)),
+                )
+            }
+
+            /// The inverse of this matrix, assuming it is a rigid body transformation, i.e. a
+            /// rotation followed by a translation, such as those created by `from_translation`,
+            /// `from_rotation_*` or `Isometry3::into_homogeneous_matrix`. This is cheaper than `inverse_affine`.
+            #[inline]
+            pub fn inverse_orthonormal(&mut self) {
+                *self = self.inversed_orthonormal();
+            }
+
+            /// The inverse of this matrix, assuming it is a rigid body transformation, i.e. a
+            /// rotation followed by a translation, such as those created by `from_translation`,
+            /// `from_rotation_*` or `Isometry3::into_homogeneous_matrix`. This is cheaper than `inversed_affine`.
+            #[inline]
+            pub fn inversed_orthonormal(&self) -> Self {
+                let a = self.cols[0].xyz();
+                let b = self.cols[1].xyz();
+                let c = self.cols[2].xyz();
+                let t = self.cols[3].xyz();
+                let zero = $t::from(0.0);
+                Self::new(
+                    $vt::new(a.x, b.x, c.x, zero),
+                    $vt::new(a.y, b.y, c.y, zero),
+                    $vt::new(a.z, b.z, c.z, zero),
+                    $vt::new(-a.dot(t), -b.dot(t), -c.dot(t), $t::from(1.0)),
+                )
+            }
+
+            /// Transform a 3d point by this matrix, assuming it is an affine transformation.
+            ///
+            /// This is the same as multiplying by the homogeneous point, but skips computing
+            /// (and dividing by) the last component.
+            #[inline]
+            pub fn transform_point3(&self, point: $v3t) -> $v3t {
+                self.cols[0].xyz() * point.x
+                    + self.cols[1].xyz() * point.y
+                    + self.cols[2].xyz() * point.z
+                    + self.cols[3].xyz()
+            }
+
+            /// Transform a 3d vector by this matrix, assuming it is an affine transformation.
+            ///
+            /// This is the same as multiplying by the homogeneous vector, i.e. translation is ignored.
+            #[inline]
+            pub fn transform_vec3(&self, vec: $v3t) -> $v3t {
+                self.cols[0].xyz() * vec.x + self.cols[1].xyz() * vec.y + self.cols[2].xyz() * vec.z
+            }
+
             #[inline]
             pub fn layout() -> Layout {
                 Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
@@ -1191,7 +1487,13 @@ macro_rules! mat4s {
     }
 }
 
-mat4s!(Mat4 => Rotor3, Bivec3, Vec4, Vec3, f32, Wat4 => WRotor3, WBivec3, Wec4, Wec3, f32x4);
+mat4s!(
+    Mat4 => Rotor3, Bivec3, Vec4, Vec3, f32,
+    Wat4 => WRotor3, WBivec3, Wec4, Wec3, f32x4,
+    Wat4x8 => WRotor3x8, WBivec3x8, Wec4x8, Wec3x8, f32x8,
+    DMat4 => DRotor3, DBivec3, DVec4, DVec3, f64,
+    DWat4 => DWRotor3, DWBivec3, DWec4, DWec3, f64x4
+);
 
 impl PartialEq for Mat4 {
     fn eq(&self, other: &Self) -> bool {
@@ -1209,6 +1511,399 @@ impl PartialEq for Mat4 {
     }
 }
 
+impl PartialEq for DMat4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cols[0] == other.cols[0]
+            && self.cols[1] == other.cols[1]
+            && self.cols[2] == other.cols[2]
+            && self.cols[3] == other.cols[3]
+    }
+}
+
+macro_rules! transposed_row {
+    ($m:expr, $vt:ident, $r:ident, [$($i:tt),+]) => {
+        $vt::new($($m.cols[$i].$r),+)
+    };
+}
+
+macro_rules! nonsquare_mats {
+    ($($n:ident => ($vt:ident, $vin:ident, $tn:ident, $t:ident, $nc:expr, $len:expr) { $($i:tt => $col:ident, $c:ident);+ } $is:tt [$($r:ident),+]),+) => {
+        $(
+        /// A non-square matrix, named by its number of columns followed by its number of rows, and
+        /// stored column-major like the square matrices.
+        ///
+        /// These are useful to store transformations more compactly, e.g. in per-instance GPU buffers,
+        /// where the last row of a homogeneous matrix is implied.
+        #[derive(Clone, Copy, Debug)]
+        #[repr(C)]
+        pub struct $n {
+            pub cols: [$vt; $nc],
+        }
+
+        derive_default_identity!($n);
+
+        impl $n {
+            #[inline]
+            pub fn new($($col: $vt),+) -> Self {
+                $n {
+                    cols: [$($col),+],
+                }
+            }
+
+            /// The transpose of this matrix, i.e. the matrix with its rows and columns swapped.
+            #[inline]
+            pub fn transposed(&self) -> $tn {
+                $tn::new($(transposed_row!(self, $vin, $r, $is)),+)
+            }
+
+            #[inline]
+            pub fn layout() -> Layout {
+                Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+            }
+
+            #[inline]
+            pub fn as_array(&self) -> &[$t; $len] {
+                use std::convert::TryInto;
+                self.as_slice().try_into().unwrap()
+            }
+
+            #[inline]
+            pub fn as_slice(&self) -> &[$t] {
+                // This is safe because we are statically bounding our slices to the size of these
+                // vectors
+                unsafe {
+                    std::slice::from_raw_parts(self as *const $n as *const $t, $len)
+                }
+            }
+
+            #[inline]
+            pub fn as_component_slice(&self) -> &[$vt] {
+                &self.cols
+            }
+
+            #[inline]
+            pub fn as_byte_slice(&self) -> &[u8] {
+                // This is safe because we are statically bounding our slices to the size of these
+                // vectors
+                unsafe {
+                    std::slice::from_raw_parts(self as *const $n as *const u8, $len * std::mem::size_of::<$t>())
+                }
+            }
+
+            #[inline]
+            pub fn as_mut_slice(&mut self) -> &mut [$t] {
+                // This is safe because we are statically bounding our slices to the size of these
+                // vectors
+                unsafe {
+                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, $len)
+                }
+            }
+
+            #[inline]
+            pub fn as_mut_component_slice(&mut self) -> &mut [$vt] {
+                &mut self.cols
+            }
+
+            #[inline]
+            pub fn as_mut_byte_slice(&mut self) -> &mut [u8] {
+                // This is safe because we are statically bounding our slices to the size of these
+                // vectors
+                unsafe {
+                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, $len * std::mem::size_of::<$t>())
+                }
+            }
+        }
+
+        impl Mul<$vin> for $n {
+            type Output = $vt;
+            #[inline]
+            fn mul(self, rhs: $vin) -> $vt {
+                $vt::zero() $(+ self.cols[$i] * rhs.$c)+
+            }
+        }
+
+        impl Mul<$t> for $n {
+            type Output = $n;
+            #[inline]
+            fn mul(self, rhs: $t) -> $n {
+                $n::new($(self.cols[$i] * rhs),+)
+            }
+        }
+
+        impl Add for $n {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: $n) -> Self {
+                $n::new($(self.cols[$i] + rhs.cols[$i]),+)
+            }
+        }
+
+        impl Sub for $n {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: $n) -> Self {
+                $n::new($(self.cols[$i] - rhs.cols[$i]),+)
+            }
+        }
+
+        impl Index<usize> for $n {
+            type Output = $vt;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.cols[index]
+            }
+        }
+
+        impl IndexMut<usize> for $n {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.cols[index]
+            }
+        }
+        )+
+    };
+}
+
+nonsquare_mats!(
+    Mat3x2 => (Vec2, Vec3, Mat2x3, f32, 3, 6) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y],
+    Mat2x3 => (Vec3, Vec2, Mat3x2, f32, 2, 6) { 0 => col1, x; 1 => col2, y } [0, 1] [x, y, z],
+    Mat4x3 => (Vec3, Vec4, Mat3x4, f32, 4, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z; 3 => col4, w } [0, 1, 2, 3] [x, y, z],
+    Mat3x4 => (Vec4, Vec3, Mat4x3, f32, 3, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y, z, w],
+
+    Wat3x2 => (Wec2, Wec3, Wat2x3, f32x4, 3, 6) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y],
+    Wat2x3 => (Wec3, Wec2, Wat3x2, f32x4, 2, 6) { 0 => col1, x; 1 => col2, y } [0, 1] [x, y, z],
+    Wat4x3 => (Wec3, Wec4, Wat3x4, f32x4, 4, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z; 3 => col4, w } [0, 1, 2, 3] [x, y, z],
+    Wat3x4 => (Wec4, Wec3, Wat4x3, f32x4, 3, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y, z, w],
+
+    Wat3x2x8 => (Wec2x8, Wec3x8, Wat2x3x8, f32x8, 3, 6) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y],
+    Wat2x3x8 => (Wec3x8, Wec2x8, Wat3x2x8, f32x8, 2, 6) { 0 => col1, x; 1 => col2, y } [0, 1] [x, y, z],
+    Wat4x3x8 => (Wec3x8, Wec4x8, Wat3x4x8, f32x8, 4, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z; 3 => col4, w } [0, 1, 2, 3] [x, y, z],
+    Wat3x4x8 => (Wec4x8, Wec3x8, Wat4x3x8, f32x8, 3, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y, z, w],
+
+    DMat3x2 => (DVec2, DVec3, DMat2x3, f64, 3, 6) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y],
+    DMat2x3 => (DVec3, DVec2, DMat3x2, f64, 2, 6) { 0 => col1, x; 1 => col2, y } [0, 1] [x, y, z],
+    DMat4x3 => (DVec3, DVec4, DMat3x4, f64, 4, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z; 3 => col4, w } [0, 1, 2, 3] [x, y, z],
+    DMat3x4 => (DVec4, DVec3, DMat4x3, f64, 3, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y, z, w],
+
+    DWat3x2 => (DWec2, DWec3, DWat2x3, f64x4, 3, 6) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y],
+    DWat2x3 => (DWec3, DWec2, DWat3x2, f64x4, 2, 6) { 0 => col1, x; 1 => col2, y } [0, 1] [x, y, z],
+    DWat4x3 => (DWec3, DWec4, DWat3x4, f64x4, 4, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z; 3 => col4, w } [0, 1, 2, 3] [x, y, z],
+    DWat3x4 => (DWec4, DWec3, DWat4x3, f64x4, 3, 12) { 0 => col1, x; 1 => col2, y; 2 => col3, z } [0, 1, 2] [x, y, z, w]
+);
+
+macro_rules! impl_mat_products {
+    ($($a:ident * $b:ident => $c:ident [$($i:tt),+]),+) => {
+        $(impl Mul<$b> for $a {
+            type Output = $c;
+            #[inline]
+            fn mul(self, rhs: $b) -> $c {
+                $c::new($(self * rhs.cols[$i]),+)
+            }
+        })+
+    };
+}
+
+macro_rules! nonsquare_products {
+    ($(($m2:ident, $m3:ident, $m4:ident, $m32:ident, $m23:ident, $m43:ident, $m34:ident)),+) => {
+        $(impl_mat_products!(
+            $m2 * $m32 => $m32 [0, 1, 2],
+            $m32 * $m3 => $m32 [0, 1, 2],
+            $m32 * $m23 => $m2 [0, 1],
+            $m3 * $m23 => $m23 [0, 1],
+            $m23 * $m2 => $m23 [0, 1],
+            $m23 * $m32 => $m3 [0, 1, 2],
+            $m3 * $m43 => $m43 [0, 1, 2, 3],
+            $m43 * $m4 => $m43 [0, 1, 2, 3],
+            $m43 * $m34 => $m3 [0, 1, 2],
+            $m4 * $m34 => $m34 [0, 1, 2],
+            $m34 * $m3 => $m34 [0, 1, 2],
+            $m34 * $m43 => $m4 [0, 1, 2, 3]
+        );)+
+    };
+}
+
+nonsquare_products!(
+    (Mat2, Mat3, Mat4, Mat3x2, Mat2x3, Mat4x3, Mat3x4),
+    (Wat2, Wat3, Wat4, Wat3x2, Wat2x3, Wat4x3, Wat3x4),
+    (Wat2x8, Wat3x8, Wat4x8, Wat3x2x8, Wat2x3x8, Wat4x3x8, Wat3x4x8),
+    (DMat2, DMat3, DMat4, DMat3x2, DMat2x3, DMat4x3, DMat3x4),
+    (DWat2, DWat3, DWat4, DWat3x2, DWat2x3, DWat4x3, DWat3x4)
+);
+
+macro_rules! affine_mats {
+    ($($n:ident => ($lt:ident, $ht:ident, $an:ident, $vt:ident, $point_fn:ident, $vec_fn:ident) [$($i:tt),+] $k:tt),+) => {
+        $(impl $n {
+            /// The identity transformation.
+            #[inline]
+            pub fn identity() -> Self {
+                let linear = $lt::identity();
+                Self::new($(linear.cols[$i],)+ $vt::zero())
+            }
+
+            /// Create an affine transformation from its linear part (rotation, scale, etc.) and a translation,
+            /// which is applied after the linear part.
+            #[inline]
+            pub fn from_linear_translation(linear: $lt, translation: $vt) -> Self {
+                Self::new($(linear.cols[$i],)+ translation)
+            }
+
+            /// The linear part of this affine transformation, i.e. all but the last column.
+            #[inline]
+            pub fn linear(&self) -> $lt {
+                $lt::new($(self.cols[$i]),+)
+            }
+
+            /// The translation part of this affine transformation, i.e. the last column.
+            #[inline]
+            pub fn translation(&self) -> $vt {
+                self.cols[$k]
+            }
+
+            /// Transform a point by this matrix, i.e. apply both the linear part and the translation.
+            #[inline]
+            pub fn $point_fn(&self, point: $vt) -> $vt {
+                self.linear() * point + self.cols[$k]
+            }
+
+            /// Transform a vector by this matrix, i.e. translation is ignored.
+            #[inline]
+            pub fn $vec_fn(&self, vec: $vt) -> $vt {
+                self.linear() * vec
+            }
+
+            /// If this matrix is not currently invertable, this function will return
+            /// an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inverse(&mut self) {
+                *self = self.inversed();
+            }
+
+            /// The inverse of this affine transformation.
+            ///
+            /// If this matrix is not currently invertable, this function will return
+            /// an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inversed(&self) -> Self {
+                let linear = self.linear().inversed();
+                Self::from_linear_translation(linear, linear * -self.cols[$k])
+            }
+
+            /// The equivalent homogeneous matrix, i.e. with the implied last row added back.
+            #[inline]
+            pub fn into_homogeneous(self) -> $ht {
+                $ht::new($(self.cols[$i].into_homogeneous_vector(),)+ self.cols[$k].into_homogeneous_point())
+            }
+        }
+
+        /// Compose two affine transformations, with the same result as multiplying the
+        /// equivalent homogeneous matrices.
+        impl Mul for $n {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::new($(self.$vec_fn(rhs.cols[$i]),)+ self.$point_fn(rhs.cols[$k]))
+            }
+        }
+
+        /// Drop the last row of a homogeneous matrix, which is assumed to be an affine transformation.
+        impl From<$ht> for $n {
+            #[inline]
+            fn from(m: $ht) -> Self {
+                Self::new($($vt::from(m.cols[$i]),)+ $vt::from(m.cols[$k]))
+            }
+        }
+
+        impl From<$n> for $ht {
+            #[inline]
+            fn from(m: $n) -> Self {
+                m.into_homogeneous()
+            }
+        }
+
+        impl From<$an> for $n {
+            #[inline]
+            fn from(affine: $an) -> Self {
+                Self::from_linear_translation(affine.linear, affine.translation)
+            }
+        }
+
+        impl From<$n> for $an {
+            #[inline]
+            fn from(m: $n) -> Self {
+                $an::new(m.translation(), m.linear())
+            }
+        })+
+    };
+}
+
+affine_mats!(
+    Mat3x2 => (Mat2, Mat3, Affine2, Vec2, transform_point2, transform_vec2) [0, 1] 2,
+    Wat3x2 => (Wat2, Wat3, WAffine2, Wec2, transform_point2, transform_vec2) [0, 1] 2,
+    Wat3x2x8 => (Wat2x8, Wat3x8, WAffine2x8, Wec2x8, transform_point2, transform_vec2) [0, 1] 2,
+    DMat3x2 => (DMat2, DMat3, DAffine2, DVec2, transform_point2, transform_vec2) [0, 1] 2,
+    DWat3x2 => (DWat2, DWat3, DWAffine2, DWec2, transform_point2, transform_vec2) [0, 1] 2,
+    Mat4x3 => (Mat3, Mat4, Affine3, Vec3, transform_point3, transform_vec3) [0, 1, 2] 3,
+    Wat4x3 => (Wat3, Wat4, WAffine3, Wec3, transform_point3, transform_vec3) [0, 1, 2] 3,
+    Wat4x3x8 => (Wat3x8, Wat4x8, WAffine3x8, Wec3x8, transform_point3, transform_vec3) [0, 1, 2] 3,
+    DMat4x3 => (DMat3, DMat4, DAffine3, DVec3, transform_point3, transform_vec3) [0, 1, 2] 3,
+    DWat4x3 => (DWat3, DWat4, DWAffine3, DWec3, transform_point3, transform_vec3) [0, 1, 2] 3
+);
+
+macro_rules! impl_transposed_identity {
+    ($($n:ident => $tn:ident),+) => {
+        $(impl $n {
+            /// The matrix with ones on its main diagonal, i.e. the transpose of the identity transformation.
+            #[inline]
+            pub fn identity() -> Self {
+                $tn::identity().transposed()
+            }
+        })+
+    };
+}
+
+impl_transposed_identity!(
+    Mat2x3 => Mat3x2,
+    Wat2x3 => Wat3x2,
+    Wat2x3x8 => Wat3x2x8,
+    DMat2x3 => DMat3x2,
+    DWat2x3 => DWat3x2,
+    Mat3x4 => Mat4x3,
+    Wat3x4 => Wat4x3,
+    Wat3x4x8 => Wat4x3x8,
+    DMat3x4 => DMat4x3,
+    DWat3x4 => DWat4x3
+);
+
+macro_rules! impl_nonsquare_partial_eq {
+    ($($n:ident [$($i:tt),+]),+) => {
+        $(impl PartialEq for $n {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.cols[$i] == other.cols[$i])&&+
+            }
+        })+
+    };
+}
+
+impl_nonsquare_partial_eq!(
+    Mat3x2 [0, 1, 2],
+    Mat2x3 [0, 1],
+    Mat4x3 [0, 1, 2, 3],
+    Mat3x4 [0, 1, 2],
+    DMat3x2 [0, 1, 2],
+    DMat2x3 [0, 1],
+    DMat4x3 [0, 1, 2, 3],
+    DMat3x4 [0, 1, 2]
+);
+
+impl_f64_conversions!(
+    Mat2 => DMat2 { cols },
+    Mat3 => DMat3 { cols },
+    Mat4 => DMat4 { cols },
+    Mat3x2 => DMat3x2 { cols },
+    Mat2x3 => DMat2x3 { cols },
+    Mat4x3 => DMat4x3 { cols },
+    Mat3x4 => DMat3x4 { cols }
+);
+
 // Utility functions for mat3 specific code
 impl Mat3 {
     pub fn translate(&mut self, translation: &Vec2) {
@@ -1266,3 +1961,182 @@ impl Wat4 {
         res
     }
 }
+
+macro_rules! impl_try_inverse {
+    ($($n:ident => $t:ident),+) => {
+        $(impl $n {
+            /// The inverse of this matrix, or `None` if the absolute value of its determinant
+            /// is not larger than `epsilon`, i.e. if the matrix is singular or close to it.
+            #[inline]
+            pub fn try_inverse(&self, epsilon: $t) -> Option<Self> {
+                if self.determinant().abs() > epsilon {
+                    Some(self.inversed())
+                } else {
+                    None
+                }
+            }
+        })+
+    };
+}
+
+impl_try_inverse!(Mat2 => f32, Mat3 => f32, Mat4 => f32, DMat2 => f64, DMat3 => f64, DMat4 => f64);
+
+macro_rules! impl_wide_try_inverse {
+    ($($n:ident => $t:ident),+) => {
+        $(impl $n {
+            /// The inverse of this matrix in each lane, along with a mask of the lanes which were invertible,
+            /// i.e. where the absolute value of the determinant is larger than `epsilon`.
+            ///
+            /// Lanes which are not invertible are set to the identity matrix rather than to an invalid inverse,
+            /// so that they do not propagate infinities or NaNs.
+            #[inline]
+            pub fn try_inverse(&self, epsilon: $t) -> (Self, $t) {
+                let mask = self.determinant().abs().cmp_gt(epsilon);
+                (<Self as Lanes>::merge(mask, self.inversed(), Self::identity()), mask)
+            }
+        })+
+    };
+}
+
+impl_wide_try_inverse!(
+    Wat2 => f32x4, Wat3 => f32x4, Wat4 => f32x4,
+    Wat2x8 => f32x8, Wat3x8 => f32x8, Wat4x8 => f32x8,
+    DWat2 => f64x4, DWat3 => f64x4, DWat4 => f64x4
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn mat2_mul_inverse() {
+        let a = Mat2::new(Vec2::new(1.0, 3.0), Vec2::new(2.0, 4.0));
+        let b = Mat2::from([[0.0, 1.0], [-1.0, 2.0]]);
+        let v = Vec2::new(-1.5, 0.5);
+        assert_eq!(a * b, Mat2::new(Vec2::new(2.0, 4.0), Vec2::new(3.0, 5.0)));
+        assert!(((a * b) * v).eq_eps(a * (b * v)));
+
+        assert!(a.determinant().eq_eps(-2.0));
+        assert!((a.adjugate() * (1.0 / a.determinant())) == a.inversed());
+        let id = a * a.inversed();
+        assert!(id.cols[0].eq_eps(Vec2::unit_x()) && id.cols[1].eq_eps(Vec2::unit_y()));
+        assert_eq!(a.transposed(), Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)));
+    }
+
+    #[test]
+    fn mat2_rotation() {
+        let v = Vec2::new(1.0, 2.0);
+        let m = Mat2::from_rotation(0.7);
+        assert!((m * v).eq_eps(Rotor2::from_angle(0.7) * v));
+        let inv = m.inversed();
+        let t = m.transposed();
+        assert!(inv.cols[0].eq_eps(t.cols[0]) && inv.cols[1].eq_eps(t.cols[1]));
+        assert!(m.determinant().eq_eps(1.0));
+    }
+
+    #[test]
+    fn wat2_inverse() {
+        let a = Mat2::new(Vec2::new(1.0, 3.0), Vec2::new(2.0, 4.0));
+        let wide = Wat2::new(Wec2::splat(a.cols[0]), Wec2::splat(a.cols[1])).inversed() * f32x4::from(2.0);
+        let expected = a.inversed() * 2.0;
+        assert!(wide.cols[0].extract(3).eq_eps(expected.cols[0]));
+        assert!(wide.cols[1].extract(3).eq_eps(expected.cols[1]));
+    }
+
+    #[test]
+    fn try_inverse() {
+        let singular = Mat3::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(2.0, 4.0, 6.0), Vec3::new(0.0, 1.0, 0.0));
+        assert!(singular.determinant().eq_eps(0.0));
+        assert!(singular.try_inverse(1e-6).is_none());
+        assert!(Mat2::from_scale(0.0).try_inverse(1e-6).is_none());
+        assert!(Mat4::from_nonuniform_scale(Vec4::new(1.0, 1.0, 0.0, 1.0)).try_inverse(1e-6).is_none());
+
+        let m = Mat3::new(Vec3::new(2.0, 0.0, 1.0), Vec3::new(1.0, 3.0, 0.0), Vec3::new(0.0, 1.0, 1.0));
+        assert!(m.determinant().eq_eps(7.0));
+        let id = m * m.try_inverse(1e-6).unwrap();
+        assert!(id.cols[0].eq_eps(Vec3::unit_x()) && id.cols[1].eq_eps(Vec3::unit_y()) && id.cols[2].eq_eps(Vec3::unit_z()));
+
+        let m4 = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0)) * m.into_homogeneous() * Mat4::from_scale_homogeneous(2.0);
+        assert!(m4.determinant().eq_eps(7.0 * 8.0));
+        let id = m4 * m4.try_inverse(1e-6).unwrap();
+        assert!(id.cols.iter().zip(Mat4::identity().cols.iter()).all(|(a, b)| a.eq_eps(*b)));
+    }
+
+    #[test]
+    fn wide_try_inverse() {
+        let m = Mat3::new(Vec3::new(2.0, 0.0, 1.0), Vec3::new(1.0, 3.0, 0.0), Vec3::new(0.0, 1.0, 1.0));
+        let singular = Mat3::from_scale(0.0);
+        let wide = Wat3::from_lanes(&[m, singular, m, singular]);
+        let (inv, mask) = wide.try_inverse(f32x4::from(1e-6));
+        assert_eq!(mask.move_mask(), 0b0101);
+        let expected = m.inversed();
+        assert!(inv.extract(2).cols.iter().zip(expected.cols.iter()).all(|(a, b)| a.eq_eps(*b)));
+        assert_eq!(inv.extract(1), Mat3::identity());
+    }
+
+    #[test]
+    fn affine_inverse() {
+        let rigid = Isometry3::new(Vec3::new(1.0, -2.0, 3.0), Rotor3::from_euler_angles(0.3, -0.8, 1.2)).into_homogeneous_matrix();
+        let affine = rigid * Mat4::from_nonuniform_scale(Vec4::new(2.0, 0.5, 3.0, 1.0));
+        let p = Vec3::new(0.5, 4.0, -1.0);
+        assert!(rigid.transform_point3(p).eq_eps((rigid * p.into_homogeneous_point()).xyz()));
+        assert!(rigid.transform_vec3(p).eq_eps((rigid * p.into_homogeneous_vector()).xyz()));
+
+        for (inv, expected) in [
+            (rigid.inversed_orthonormal(), rigid.inversed()),
+            (affine.inversed_affine(), affine.inversed()),
+        ] {
+            assert!(inv.cols.iter().zip(expected.cols.iter()).all(|(a, b)| a.eq_eps(*b)));
+        }
+
+        let wide = Wat4::from_lanes(&[affine; 4]).inversed_affine();
+        assert!(wide.transform_point3(Wec3::splat(p)).extract(0).eq_eps(affine.inversed().transform_point3(p)));
+    }
+
+    #[test]
+    fn affine_inverse_2d() {
+        let rigid = Mat3::from_translation(Vec2::new(1.0, -2.0)) * Mat3::from_rotation_z(0.7);
+        let affine = rigid * Mat3::from_nonuniform_scale(Vec3::new(2.0, 0.5, 1.0));
+        let p = Vec2::new(0.5, 4.0);
+        assert!(affine.transform_point2(p).eq_eps((affine * p.into_homogeneous_point()).xy()));
+        assert!(affine.transform_vec2(p).eq_eps((affine * p.into_homogeneous_vector()).xy()));
+        assert!(affine.inversed_affine().transform_point2(affine.transform_point2(p)).eq_eps(p));
+        assert!(rigid.inversed_orthonormal().transform_point2(rigid.transform_point2(p)).eq_eps(p));
+    }
+
+    #[test]
+    fn nonsquare_affine() {
+        let m = Mat4::from_trs(
+            Vec3::new(1.0, -2.0, 3.0),
+            Rotor3::from_euler_angles(0.3, -0.8, 1.2),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        let n = Mat4::from_translation(Vec3::new(0.0, 4.0, 1.0)) * Mat4::from_rotation_y(0.4);
+        let (a, b) = (Mat4x3::from(m), Mat4x3::from(n));
+        let p = Vec3::new(0.5, 4.0, -1.0);
+        assert_eq!(a.into_homogeneous(), m);
+        assert!((a * b).transform_point3(p).eq_eps((m * n).transform_point3(p)));
+        assert!(a.inversed().transform_point3(a.transform_point3(p)).eq_eps(p));
+        assert!(a.transform_vec3(p).eq_eps(a * p.into_homogeneous_vector()));
+
+        let rows = a.transposed();
+        assert_eq!(rows.transposed(), a);
+        assert!((rows.transposed() * p.into_homogeneous_point()).eq_eps(a.transform_point3(p)));
+
+        let wide = Wat4x3::from_lanes(&[a, b, a, b]);
+        assert!(wide.inversed().extract(1).transform_point3(p).eq_eps(b.inversed().transform_point3(p)));
+    }
+
+    #[test]
+    fn nonsquare_products() {
+        let a = Mat3x2::from(Mat3::from_translation(Vec2::new(1.0, -2.0)) * Mat3::from_rotation_z(0.7));
+        let b = Mat2x3::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.5, 2.0));
+        let v = Vec2::new(0.5, 4.0);
+        assert!(((a * b) * v).eq_eps(a * (b * v)));
+        assert!(((b * a) * Vec3::unit_z()).eq_eps(b * a.translation()));
+        assert!(((Mat2::from_scale(2.0) * a) * Vec3::unit_x()).eq_eps(a.cols[0] * 2.0));
+        assert_eq!(Mat2x3::identity(), Mat3x2::identity().transposed());
+        assert!(Affine2::from(a).transform_point(v).eq_eps(a.transform_point2(v)));
+    }
+}
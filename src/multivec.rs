@@ -0,0 +1,416 @@
+//! General multivectors, i.e. linear combinations of blades of every grade.
+//!
+//! A multivector in 3d is the sum of a scalar, a vector, a bivector and a trivector. Scalars, vectors,
+//! bivectors and trivectors (and rotors, which are the sum of a scalar and a bivector) are all special
+//! cases of multivectors, and all of them can be converted into a `Multivec3` using `From`.
+//!
+//! The main reason to reach for a multivector rather than one of the more specific types is to get access
+//! to the full set of products of the algebra:
+//!
+//! * The *geometric* product `a * b` (also available as `a.geom(b)`) is the fundamental product of the
+//!   algebra, from which all the others can be derived.
+//! * The *outer* (aka wedge or exterior) product `a.wedge(b)` joins subspaces, i.e. the wedge of two
+//!   vectors is the plane containing both.
+//! * The *inner* product `a.inner(b)` (specifically the left contraction `a ⌋ b`) removes `a` from `b`, i.e.
+//!   the inner product of a vector and a plane containing it is the vector in the plane perpendicular to it.
+//! * The *regressive* product `a.meet(b)` intersects subspaces, i.e. the meet of two planes is the line
+//!   along which they intersect.
+//!
+//! Together with the inverse of a blade, these let you perform projections, rejections and intersections
+//! directly on vectors, planes and volumes without having to fall back to cross products. Note that the
+//! multivector products compute every component of the result, so they are slower than the equivalent
+//! specialized functions on the individual types.
+use wide::{f32x4, f32x8, f64x4};
+
+use crate::util::*;
+use crate::*;
+
+use std::alloc::Layout;
+use std::ops::*;
+
+macro_rules! multivec3s {
+    ($($mn:ident => ($vt:ident, $bt:ident, $tt:ident, $rt:ident, $t:ident)),+) => {
+        $(
+        /// A general multivector in 3d space.
+        ///
+        /// Please see the module level documentation for more information on multivectors!
+        #[derive(Clone, Copy, Debug, Default)]
+        #[repr(C)]
+        pub struct $mn {
+            pub s: $t,
+            pub v: $vt,
+            pub bv: $bt,
+            pub tv: $tt,
+        }
+
+        impl $mn {
+            #[inline]
+            pub fn new(s: $t, v: $vt, bv: $bt, tv: $tt) -> Self {
+                Self {
+                    s, v, bv, tv
+                }
+            }
+
+            #[inline]
+            pub fn zero() -> Self {
+                Self::new($t::from(0.0), $vt::zero(), $bt::zero(), $tt::zero())
+            }
+
+            /// Extract the part of this multivector with the given grade, i.e.
+            /// 0 for the scalar part, 1 for the vector part, 2 for the bivector part
+            /// and 3 for the trivector part. All other grades are zero.
+            #[inline]
+            pub fn grade(&self, grade: usize) -> Self {
+                let mut res = Self::zero();
+                match grade {
+                    0 => res.s = self.s,
+                    1 => res.v = self.v,
+                    2 => res.bv = self.bv,
+                    3 => res.tv = self.tv,
+                    _ => (),
+                }
+                res
+            }
+
+            /// The reverse of this multivector, which reverses the order of the vectors
+            /// making up each blade. This negates the bivector and trivector parts.
+            #[inline]
+            pub fn reverse(&self) -> Self {
+                Self::new(self.s, self.v, -self.bv, -self.tv)
+            }
+
+            /// The (Hodge) dual of this multivector, which maps each blade to the blade
+            /// representing its orthogonal complement. Applying it twice is the identity.
+            #[inline]
+            pub fn dual(&self) -> Self {
+                Self::new(self.tv.dual(), self.bv.dual(), self.v.dual(), $tt::new(self.s))
+            }
+
+            /// The squared magnitude of this multivector, i.e. the sum of the squares
+            /// of all of its components.
+            #[inline]
+            pub fn mag_sq(&self) -> $t {
+                self.s.mul_add(self.s, self.v.mag_sq() + self.bv.mag_sq() + self.tv.mag_sq())
+            }
+
+            #[inline]
+            pub fn mag(&self) -> $t {
+                self.mag_sq().sqrt()
+            }
+
+            /// The inverse of this multivector under the geometric product, assuming it is a *blade*
+            /// (the wedge product of some number of vectors) or a *versor* (the geometric product of some
+            /// number of vectors, such as a rotor). This is not the inverse of a general multivector.
+            #[inline]
+            pub fn inverse(&self) -> Self {
+                self.reverse() / self.mag_sq()
+            }
+
+            /// The geometric product of this and another multivector.
+            #[inline]
+            pub fn geom(&self, b: Self) -> Self {
+                let a = self;
+                Self::new(
+                    a.s.mul_add(b.s, a.v.x.mul_add(b.v.x, a.v.y.mul_add(b.v.y, a.v.z.mul_add(b.v.z, (-a.bv.xy).mul_add(b.bv.xy, (-a.bv.xz).mul_add(b.bv.xz, (-a.bv.yz).mul_add(b.bv.yz, -(a.tv.xyz * b.tv.xyz)))))))),
+                    $vt::new(
+                        a.s.mul_add(b.v.x, a.v.x.mul_add(b.s, (-a.v.y).mul_add(b.bv.xy, (-a.v.z).mul_add(b.bv.xz, a.bv.xy.mul_add(b.v.y, a.bv.xz.mul_add(b.v.z, (-a.bv.yz).mul_add(b.tv.xyz, -(a.tv.xyz * b.bv.yz)))))))),
+                        a.s.mul_add(b.v.y, a.v.x.mul_add(b.bv.xy, a.v.y.mul_add(b.s, (-a.v.z).mul_add(b.bv.yz, (-a.bv.xy).mul_add(b.v.x, a.bv.xz.mul_add(b.tv.xyz, a.bv.yz.mul_add(b.v.z, a.tv.xyz * b.bv.xz))))))),
+                        a.s.mul_add(b.v.z, a.v.x.mul_add(b.bv.xz, a.v.y.mul_add(b.bv.yz, a.v.z.mul_add(b.s, (-a.bv.xy).mul_add(b.tv.xyz, (-a.bv.xz).mul_add(b.v.x, (-a.bv.yz).mul_add(b.v.y, -(a.tv.xyz * b.bv.xy)))))))),
+                    ),
+                    $bt::new(
+                        a.s.mul_add(b.bv.xy, a.v.x.mul_add(b.v.y, (-a.v.y).mul_add(b.v.x, a.v.z.mul_add(b.tv.xyz, a.bv.xy.mul_add(b.s, (-a.bv.xz).mul_add(b.bv.yz, a.bv.yz.mul_add(b.bv.xz, a.tv.xyz * b.v.z))))))),
+                        a.s.mul_add(b.bv.xz, a.v.x.mul_add(b.v.z, (-a.v.y).mul_add(b.tv.xyz, (-a.v.z).mul_add(b.v.x, a.bv.xy.mul_add(b.bv.yz, a.bv.xz.mul_add(b.s, (-a.bv.yz).mul_add(b.bv.xy, -(a.tv.xyz * b.v.y)))))))),
+                        a.s.mul_add(b.bv.yz, a.v.x.mul_add(b.tv.xyz, a.v.y.mul_add(b.v.z, (-a.v.z).mul_add(b.v.y, (-a.bv.xy).mul_add(b.bv.xz, a.bv.xz.mul_add(b.bv.xy, a.bv.yz.mul_add(b.s, a.tv.xyz * b.v.x))))))),
+                    ),
+                    $tt::new(a.s.mul_add(b.tv.xyz, a.v.x.mul_add(b.bv.yz, (-a.v.y).mul_add(b.bv.xz, a.v.z.mul_add(b.bv.xy, a.bv.xy.mul_add(b.v.z, (-a.bv.xz).mul_add(b.v.y, a.bv.yz.mul_add(b.v.x, a.tv.xyz * b.s)))))))),
+                )
+            }
+
+            /// The outer (aka wedge or exterior) product of this and another multivector.
+            #[inline]
+            pub fn wedge(&self, b: Self) -> Self {
+                let a = self;
+                Self::new(
+                    a.s * b.s,
+                    $vt::new(
+                        a.s.mul_add(b.v.x, a.v.x * b.s),
+                        a.s.mul_add(b.v.y, a.v.y * b.s),
+                        a.s.mul_add(b.v.z, a.v.z * b.s),
+                    ),
+                    $bt::new(
+                        a.s.mul_add(b.bv.xy, a.v.x.mul_add(b.v.y, (-a.v.y).mul_add(b.v.x, a.bv.xy * b.s))),
+                        a.s.mul_add(b.bv.xz, a.v.x.mul_add(b.v.z, (-a.v.z).mul_add(b.v.x, a.bv.xz * b.s))),
+                        a.s.mul_add(b.bv.yz, a.v.y.mul_add(b.v.z, (-a.v.z).mul_add(b.v.y, a.bv.yz * b.s))),
+                    ),
+                    $tt::new(a.s.mul_add(b.tv.xyz, a.v.x.mul_add(b.bv.yz, (-a.v.y).mul_add(b.bv.xz, a.v.z.mul_add(b.bv.xy, a.bv.xy.mul_add(b.v.z, (-a.bv.xz).mul_add(b.v.y, a.bv.yz.mul_add(b.v.x, a.tv.xyz * b.s)))))))),
+                )
+            }
+
+            /// The inner product of this and another multivector, specifically the left contraction
+            /// `self ⌋ b`, which is zero wherever the grade of `self` is larger than the grade of `b`.
+            #[inline]
+            pub fn inner(&self, b: Self) -> Self {
+                let a = self;
+                Self::new(
+                    a.s.mul_add(b.s, a.v.x.mul_add(b.v.x, a.v.y.mul_add(b.v.y, a.v.z.mul_add(b.v.z, (-a.bv.xy).mul_add(b.bv.xy, (-a.bv.xz).mul_add(b.bv.xz, (-a.bv.yz).mul_add(b.bv.yz, -(a.tv.xyz * b.tv.xyz)))))))),
+                    $vt::new(
+                        a.s.mul_add(b.v.x, (-a.v.y).mul_add(b.bv.xy, (-a.v.z).mul_add(b.bv.xz, -(a.bv.yz * b.tv.xyz)))),
+                        a.s.mul_add(b.v.y, a.v.x.mul_add(b.bv.xy, (-a.v.z).mul_add(b.bv.yz, a.bv.xz * b.tv.xyz))),
+                        a.s.mul_add(b.v.z, a.v.x.mul_add(b.bv.xz, a.v.y.mul_add(b.bv.yz, -(a.bv.xy * b.tv.xyz)))),
+                    ),
+                    $bt::new(
+                        a.s.mul_add(b.bv.xy, a.v.z * b.tv.xyz),
+                        a.s.mul_add(b.bv.xz, -(a.v.y * b.tv.xyz)),
+                        a.s.mul_add(b.bv.yz, a.v.x * b.tv.xyz),
+                    ),
+                    $tt::new(a.s * b.tv.xyz),
+                )
+            }
+
+            /// The regressive product of this and another multivector, which is the dual of the wedge
+            /// of the duals. For blades, this results in their intersection, i.e. the meet of two
+            /// planes is the line along which they intersect.
+            #[inline]
+            pub fn meet(&self, b: Self) -> Self {
+                self.dual().wedge(b.dual()).dual()
+            }
+
+            /// Project this blade onto the given blade, i.e. compute `(self ⌋ b) b⁻¹`.
+            ///
+            /// For example, projecting a vector onto a bivector gives the component of
+            /// the vector which lies in the plane of the bivector.
+            #[inline]
+            pub fn project_onto(&self, b: Self) -> Self {
+                self.inner(b).geom(b.inverse())
+            }
+
+            /// Reject this blade from the given blade, i.e. the part of `self` which
+            /// is not contained in `b`. This is `self` minus its projection onto `b`.
+            #[inline]
+            pub fn reject_from(&self, b: Self) -> Self {
+                *self - self.project_onto(b)
+            }
+
+            #[inline]
+            pub fn layout() -> Layout {
+                Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+            }
+        }
+
+        impl EqualsEps for $mn {
+            fn eq_eps(self, other: Self) -> bool {
+                self.s.eq_eps(other.s) && self.v.eq_eps(other.v) && self.bv.eq_eps(other.bv) && self.tv.eq_eps(other.tv)
+            }
+        }
+
+        impl From<$t> for $mn {
+            #[inline]
+            fn from(s: $t) -> Self {
+                Self::new(s, $vt::zero(), $bt::zero(), $tt::zero())
+            }
+        }
+
+        impl From<$vt> for $mn {
+            #[inline]
+            fn from(v: $vt) -> Self {
+                Self::new($t::from(0.0), v, $bt::zero(), $tt::zero())
+            }
+        }
+
+        impl From<$bt> for $mn {
+            #[inline]
+            fn from(bv: $bt) -> Self {
+                Self::new($t::from(0.0), $vt::zero(), bv, $tt::zero())
+            }
+        }
+
+        impl From<$tt> for $mn {
+            #[inline]
+            fn from(tv: $tt) -> Self {
+                Self::new($t::from(0.0), $vt::zero(), $bt::zero(), tv)
+            }
+        }
+
+        impl From<$rt> for $mn {
+            #[inline]
+            fn from(r: $rt) -> Self {
+                Self::new(r.s, $vt::zero(), r.bv, $tt::zero())
+            }
+        }
+
+        /// The geometric product of two multivectors.
+        impl Mul for $mn {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.geom(rhs)
+            }
+        }
+
+        impl Add for $mn {
+            type Output = Self;
+            #[inline]
+            fn add(mut self, rhs: $mn) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl AddAssign for $mn {
+            #[inline]
+            fn add_assign(&mut self, rhs: $mn) {
+                self.s += rhs.s;
+                self.v += rhs.v;
+                self.bv += rhs.bv;
+                self.tv += rhs.tv;
+            }
+        }
+
+        impl Sub for $mn {
+            type Output = Self;
+            #[inline]
+            fn sub(mut self, rhs: $mn) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl SubAssign for $mn {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $mn) {
+                self.s -= rhs.s;
+                self.v -= rhs.v;
+                self.bv -= rhs.bv;
+                self.tv -= rhs.tv;
+            }
+        }
+
+        impl Mul<$mn> for $t {
+            type Output = $mn;
+            #[inline]
+            fn mul(self, mut rhs: $mn) -> $mn {
+                rhs *= self;
+                rhs
+            }
+        }
+
+        impl Mul<$t> for $mn {
+            type Output = Self;
+            #[inline]
+            fn mul(mut self, rhs: $t) -> Self {
+                self *= rhs;
+                self
+            }
+        }
+
+        impl MulAssign<$t> for $mn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                self.s *= rhs;
+                self.v *= rhs;
+                self.bv *= rhs;
+                self.tv *= rhs;
+            }
+        }
+
+        impl Div<$t> for $mn {
+            type Output = Self;
+            #[inline]
+            fn div(mut self, rhs: $t) -> Self {
+                self /= rhs;
+                self
+            }
+        }
+
+        impl DivAssign<$t> for $mn {
+            #[inline]
+            fn div_assign(&mut self, rhs: $t) {
+                self.s /= rhs;
+                self.v /= rhs;
+                self.bv /= rhs;
+                self.tv /= rhs;
+            }
+        }
+
+        impl Neg for $mn {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self::new(-self.s, -self.v, -self.bv, -self.tv)
+            }
+        }
+        )+
+    }
+}
+
+multivec3s!(
+    Multivec3 => (Vec3, Bivec3, Trivec3, Rotor3, f32),
+    WMultivec3 => (Wec3, WBivec3, WTrivec3, WRotor3, f32x4),
+    WMultivec3x8 => (Wec3x8, WBivec3x8, WTrivec3x8, WRotor3x8, f32x8),
+    DMultivec3 => (DVec3, DBivec3, DTrivec3, DRotor3, f64),
+    DWMultivec3 => (DWec3, DWBivec3, DWTrivec3, DWRotor3, f64x4)
+);
+
+impl PartialEq for Multivec3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.s == other.s && self.v == other.v && self.bv == other.bv && self.tv == other.tv
+    }
+}
+
+impl PartialEq for DMultivec3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.s == other.s && self.v == other.v && self.bv == other.bv && self.tv == other.tv
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn geom_of_vectors() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(-2.0, 0.5, 4.0);
+        let r = a.geom(b);
+        let m = Multivec3::from(a) * Multivec3::from(b);
+        assert!(m.eq_eps(Multivec3::from(r)));
+    }
+
+    #[test]
+    fn wedge_and_dual() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(-2.0, 0.5, 4.0);
+        let c = Vec3::new(0.3, -1.0, 2.0);
+        assert!(a.wedge(b).dual().eq_eps(a.cross(b)));
+        assert!(a.dual().dual().eq_eps(a));
+        assert!(a.wedge(b).wedge(c).eq_eps(Trivec3::new(a.cross(b).dot(c))));
+
+        let m = Multivec3::from(a).wedge(Multivec3::from(b)).wedge(Multivec3::from(c));
+        assert!(m.eq_eps(Multivec3::from(a.wedge(b).wedge(c))));
+    }
+
+    #[test]
+    fn project_reject() {
+        let v = Multivec3::from(Vec3::new(1.0, 2.0, 3.0));
+        let plane = Multivec3::from(Bivec3::unit_xy() * 2.0);
+        assert!(v.project_onto(plane).eq_eps(Multivec3::from(Vec3::new(1.0, 2.0, 0.0))));
+        assert!(v.reject_from(plane).eq_eps(Multivec3::from(Vec3::new(0.0, 0.0, 3.0))));
+    }
+
+    #[test]
+    fn meet_planes() {
+        let xy = Multivec3::from(Bivec3::unit_xy());
+        let yz = Multivec3::from(Bivec3::unit_yz());
+        let line = xy.meet(yz);
+        assert!(line.eq_eps(Multivec3::from(Vec3::new(0.0, 1.0, 0.0))));
+    }
+
+    #[test]
+    fn wide_geom() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(-2.0, 0.5, 4.0);
+        let m = Multivec3::from(a).geom(Multivec3::from(b.dual()));
+        let wm = WMultivec3::from(Wec3::splat(a)).geom(WMultivec3::from(Wec3::splat(b).dual()));
+        assert!(wm.extract(2).eq_eps(m));
+    }
+}
@@ -0,0 +1,968 @@
+//! Projective geometric algebra (PGA) for 3d Euclidean space.
+//!
+//! 3d PGA extends the geometric algebra of 3d space with a fourth basis vector, `e0`, which squares to zero.
+//! This extra dimension makes it possible to represent *flat* elements that do not pass through the origin,
+//! as well as translations, in the same algebra as rotations:
+//!
+//! * A [`Plane3`] is a vector `e1 x + e2 y + e3 z + e0 d`, and represents the plane `x*X + y*Y + z*Z + d = 0`.
+//! * A [`Line3`] is a bivector. Its Euclidean part (`e23`, `e31`, `e12`) holds the direction of the line,
+//!   and its ideal part (`e01`, `e02`, `e03`) holds the moment of the line about the origin (the Plücker coordinates).
+//! * A [`Point3`] is a trivector `e032 x + e013 y + e021 z + e123 w`, i.e. the homogeneous point `(x/w, y/w, z/w)`.
+//!   Points with `w` equal to zero are points at infinity, i.e. directions.
+//! * A [`Motor3`] is an element of the even subalgebra, and represents any rigid body transformation, i.e.
+//!   a *screw motion* which rotates around a line and translates along it at the same time. Motors are
+//!   to isometries what rotors are to rotations, and the Euclidean part of a motor is exactly a [`Rotor3`].
+//!
+//! Elements are combined using the *meet* (outer product), which intersects them, and the *join* (regressive
+//! product), which creates the smallest element containing both, i.e. the join of two points is the line through
+//! them, and the meet of two planes is the line along which they intersect.
+//!
+//! All of the elements are homogeneous, so scaling one by a non-zero factor does not change the element it represents.
+//! Some operations, such as the conversions back into Euclidean types, divide by the weight of the element.
+use wide::{f32x4, f32x8, f64x4};
+
+use crate::geometry::Plane;
+use crate::util::*;
+use crate::*;
+
+use std::ops::*;
+
+macro_rules! pgas {
+    ($($pn:ident, $ln:ident, $pln:ident, $mn:ident => ($vt:ident, $bt:ident, $rt:ident, $isot:ident, $t:ident)),+) => {
+        $(
+        /// A point in 3d projective geometric algebra, i.e. a trivector.
+        ///
+        /// Please see the module level documentation for more information!
+        #[derive(Clone, Copy, Debug, Default)]
+        #[repr(C)]
+        pub struct $pn {
+            pub e032: $t,
+            pub e013: $t,
+            pub e021: $t,
+            pub e123: $t,
+        }
+
+        /// A line in 3d projective geometric algebra, i.e. a bivector.
+        ///
+        /// Please see the module level documentation for more information!
+        #[derive(Clone, Copy, Debug, Default)]
+        #[repr(C)]
+        pub struct $ln {
+            pub e01: $t,
+            pub e02: $t,
+            pub e03: $t,
+            pub e23: $t,
+            pub e31: $t,
+            pub e12: $t,
+        }
+
+        /// A plane in 3d projective geometric algebra, i.e. a vector.
+        ///
+        /// Please see the module level documentation for more information!
+        #[derive(Clone, Copy, Debug, Default)]
+        #[repr(C)]
+        pub struct $pln {
+            pub e1: $t,
+            pub e2: $t,
+            pub e3: $t,
+            pub e0: $t,
+        }
+
+        /// A motor in 3d projective geometric algebra, i.e. a rigid body transformation.
+        ///
+        /// Please see the module level documentation for more information!
+        #[derive(Clone, Copy, Debug)]
+        #[repr(C)]
+        pub struct $mn {
+            pub s: $t,
+            pub e23: $t,
+            pub e31: $t,
+            pub e12: $t,
+            pub e01: $t,
+            pub e02: $t,
+            pub e03: $t,
+            pub e0123: $t,
+        }
+
+        derive_default_identity!($mn);
+
+        impl $pn {
+            #[inline]
+            pub fn new(x: $t, y: $t, z: $t, w: $t) -> Self {
+                Self {
+                    e032: x,
+                    e013: y,
+                    e021: z,
+                    e123: w,
+                }
+            }
+
+            /// Create the (finite) point at the given position.
+            #[inline]
+            pub fn from_vec(v: $vt) -> Self {
+                Self::new(v.x, v.y, v.z, $t::from(1.0))
+            }
+
+            /// Create the point at infinity in the given direction.
+            #[inline]
+            pub fn from_direction(v: $vt) -> Self {
+                Self::new(v.x, v.y, v.z, $t::from(0.0))
+            }
+
+            /// The position of this point, i.e. its homogeneous coordinates divided by its weight.
+            ///
+            /// This is not meaningful for points at infinity.
+            #[inline]
+            pub fn into_vec(self) -> $vt {
+                $vt::new(self.e032, self.e013, self.e021) / self.e123
+            }
+
+            /// Scale this point so that its weight is one.
+            #[inline]
+            pub fn normalized(&self) -> Self {
+                let w = self.e123;
+                Self::new(self.e032 / w, self.e013 / w, self.e021 / w, $t::from(1.0))
+            }
+
+            /// The join of two points, i.e. the line going from `self` to `other`.
+            #[inline]
+            pub fn join(&self, other: $pn) -> $ln {
+                let a = $vt::new(self.e032, self.e013, self.e021);
+                let b = $vt::new(other.e032, other.e013, other.e021);
+                $ln::from_direction_moment(
+                    b * self.e123 - a * other.e123,
+                    a.cross(b),
+                )
+            }
+
+            /// Project this point onto a plane, i.e. find the point on the plane closest to it.
+            #[inline]
+            pub fn project_onto_plane(&self, plane: $pln) -> Self {
+                let n = plane.normal();
+                let p = $vt::new(self.e032, self.e013, self.e021);
+                let dist = n.dot(p) + plane.e0 * self.e123;
+                let mag_sq = n.mag_sq();
+                let res = p * mag_sq - n * dist;
+                Self::new(res.x, res.y, res.z, self.e123 * mag_sq)
+            }
+
+            /// Project this point onto a line, i.e. find the point on the line closest to it.
+            #[inline]
+            pub fn project_onto_line(&self, line: $ln) -> Self {
+                let u = line.direction();
+                let p = $vt::new(self.e032, self.e013, self.e021);
+                let res = u.cross(line.moment()) * self.e123 + u * u.dot(p);
+                Self::new(res.x, res.y, res.z, self.e123 * u.mag_sq())
+            }
+        }
+
+        impl From<$vt> for $pn {
+            #[inline]
+            fn from(v: $vt) -> Self {
+                Self::from_vec(v)
+            }
+        }
+
+        impl $ln {
+            #[inline]
+            pub fn new(e01: $t, e02: $t, e03: $t, e23: $t, e31: $t, e12: $t) -> Self {
+                Self {
+                    e01, e02, e03, e23, e31, e12
+                }
+            }
+
+            /// Create a line from its Plücker coordinates, i.e. its direction and its moment
+            /// about the origin (the cross product of any point on the line with its direction).
+            #[inline]
+            pub fn from_direction_moment(direction: $vt, moment: $vt) -> Self {
+                Self::new(moment.x, moment.y, moment.z, direction.x, direction.y, direction.z)
+            }
+
+            /// Create the line passing through `point`, going in `direction`.
+            #[inline]
+            pub fn from_point_direction(point: $vt, direction: $vt) -> Self {
+                Self::from_direction_moment(direction, point.cross(direction))
+            }
+
+            /// The direction of this line, i.e. its Euclidean part.
+            #[inline]
+            pub fn direction(&self) -> $vt {
+                $vt::new(self.e23, self.e31, self.e12)
+            }
+
+            /// The moment of this line about the origin, i.e. its ideal part.
+            #[inline]
+            pub fn moment(&self) -> $vt {
+                $vt::new(self.e01, self.e02, self.e03)
+            }
+
+            /// The magnitude of this line, i.e. the length of its direction.
+            #[inline]
+            pub fn mag(&self) -> $t {
+                self.direction().mag()
+            }
+
+            /// Scale this line so that its direction has unit length.
+            #[inline]
+            pub fn normalized(&self) -> Self {
+                let mag = self.mag();
+                Self::from_direction_moment(self.direction() / mag, self.moment() / mag)
+            }
+
+            /// The point on this line which is closest to the origin.
+            #[inline]
+            pub fn closest_point_to_origin(&self) -> $vt {
+                let u = self.direction();
+                u.cross(self.moment()) / u.mag_sq()
+            }
+
+            /// The join of this line and a point, i.e. the plane containing both.
+            #[inline]
+            pub fn join(&self, point: $pn) -> $pln {
+                let u = self.direction();
+                let m = self.moment();
+                let p = $vt::new(point.e032, point.e013, point.e021);
+                let n = m * point.e123 + u.cross(p);
+                $pln::new(n.x, n.y, n.z, -m.dot(p))
+            }
+
+            /// The meet of this line and a plane, i.e. the point at which they intersect.
+            ///
+            /// If the line is parallel to the plane, this is a point at infinity.
+            #[inline]
+            pub fn meet(&self, plane: $pln) -> $pn {
+                let u = self.direction();
+                let n = plane.normal();
+                let p = n.cross(self.moment()) - u * plane.e0;
+                $pn::new(p.x, p.y, p.z, n.dot(u))
+            }
+
+            /// Project this line onto a plane.
+            #[inline]
+            pub fn project_onto_plane(&self, plane: $pln) -> Self {
+                let u = self.direction();
+                let n = plane.normal();
+                Self::from_direction_moment(
+                    u * n.mag_sq() - n * n.dot(u),
+                    n * n.dot(self.moment()) - n.cross(u) * plane.e0,
+                )
+            }
+        }
+
+        impl $pln {
+            /// Create the plane `e1 * x + e2 * y + e3 * z + e0 = 0`.
+            #[inline]
+            pub fn new(e1: $t, e2: $t, e3: $t, e0: $t) -> Self {
+                Self {
+                    e1, e2, e3, e0
+                }
+            }
+
+            /// Create the plane passing through `point` with the given normal.
+            #[inline]
+            pub fn from_point_normal(point: $vt, normal: $vt) -> Self {
+                Self::new(normal.x, normal.y, normal.z, -normal.dot(point))
+            }
+
+            /// The normal of this plane, i.e. its Euclidean part.
+            #[inline]
+            pub fn normal(&self) -> $vt {
+                $vt::new(self.e1, self.e2, self.e3)
+            }
+
+            /// The magnitude of this plane, i.e. the length of its normal.
+            #[inline]
+            pub fn mag(&self) -> $t {
+                self.normal().mag()
+            }
+
+            /// Scale this plane so that its normal has unit length.
+            #[inline]
+            pub fn normalized(&self) -> Self {
+                let mag = self.mag();
+                Self::new(self.e1 / mag, self.e2 / mag, self.e3 / mag, self.e0 / mag)
+            }
+
+            /// The signed distance from this plane to a point, assuming this plane is normalized.
+            ///
+            /// The distance is positive on the side the normal is pointing towards.
+            #[inline]
+            pub fn signed_distance(&self, point: $vt) -> $t {
+                self.normal().dot(point) + self.e0
+            }
+
+            /// The meet of two planes, i.e. the line along which they intersect.
+            ///
+            /// The direction of the line is the cross product of the normals of the planes.
+            #[inline]
+            pub fn meet(&self, other: $pln) -> $ln {
+                let a = self.normal();
+                let b = other.normal();
+                $ln::from_direction_moment(
+                    a.cross(b),
+                    b * self.e0 - a * other.e0,
+                )
+            }
+        }
+
+        impl $mn {
+            #[inline]
+            #[allow(clippy::too_many_arguments)]
+            pub fn new(s: $t, e23: $t, e31: $t, e12: $t, e01: $t, e02: $t, e03: $t, e0123: $t) -> Self {
+                Self {
+                    s, e23, e31, e12, e01, e02, e03, e0123
+                }
+            }
+
+            #[inline]
+            pub fn identity() -> Self {
+                Self::from_rotor($rt::identity())
+            }
+
+            /// Create a motor which only rotates, as the given rotor does.
+            #[inline]
+            pub fn from_rotor(rotor: $rt) -> Self {
+                let zero = $t::from(0.0);
+                Self::new(rotor.s, rotor.bv.yz, -rotor.bv.xz, rotor.bv.xy, zero, zero, zero, zero)
+            }
+
+            /// Create a motor which only translates by `translation`.
+            #[inline]
+            pub fn from_translation(translation: $vt) -> Self {
+                let zero = $t::from(0.0);
+                let t = translation * $t::from(-0.5);
+                Self::new($t::from(1.0), zero, zero, zero, t.x, t.y, t.z, zero)
+            }
+
+            /// Create a motor which performs the same transformation as `iso`, i.e.
+            /// a rotation *and then* a translation.
+            #[inline]
+            pub fn from_isometry(iso: $isot) -> Self {
+                Self::from_translation(iso.translation) * Self::from_rotor(iso.rotation)
+            }
+
+            /// Create a screw motion, which rotates by `angle` around `line` and translates by `distance` along it,
+            /// both following the direction of the line. `line` must be normalized.
+            #[inline]
+            pub fn from_screw(line: $ln, angle: $t, distance: $t) -> Self {
+                let (sin, cos) = (angle * $t::from(0.5)).sin_cos();
+                let rotation = Self::new(
+                    cos,
+                    -line.e23 * sin,
+                    -line.e31 * sin,
+                    -line.e12 * sin,
+                    -line.e01 * sin,
+                    -line.e02 * sin,
+                    -line.e03 * sin,
+                    $t::from(0.0),
+                );
+                Self::from_translation(line.direction() * distance) * rotation
+            }
+
+            /// The rotational part of this motor.
+            #[inline]
+            pub fn rotor(&self) -> $rt {
+                $rt::new(self.s, $bt::new(self.e12, -self.e31, self.e23))
+            }
+
+            /// The translational part of this motor, assuming it is normalized.
+            #[inline]
+            pub fn translation(&self) -> $vt {
+                $vt::new(
+                    self.s.mul_add(self.e01, self.e23.mul_add(self.e0123, self.e12.mul_add(self.e02, -(self.e31 * self.e03)))),
+                    self.s.mul_add(self.e02, self.e23.mul_add(self.e03, self.e31.mul_add(self.e0123, -(self.e12 * self.e01)))),
+                    self.s.mul_add(self.e03, self.e31.mul_add(self.e01, self.e12.mul_add(self.e0123, -(self.e23 * self.e02)))),
+                ) * $t::from(-2.0)
+            }
+
+            /// Convert this motor into the equivalent isometry, assuming it is normalized.
+            #[inline]
+            pub fn into_isometry(self) -> $isot {
+                $isot::new(self.translation(), self.rotor())
+            }
+
+            #[inline]
+            pub fn reverse(&mut self) {
+                *self = self.reversed();
+            }
+
+            #[inline]
+            pub fn reversed(&self) -> Self {
+                Self::new(self.s, -self.e23, -self.e31, -self.e12, -self.e01, -self.e02, -self.e03, self.e0123)
+            }
+
+            /// The magnitude of this motor, i.e. the magnitude of its rotational part.
+            #[inline]
+            pub fn mag(&self) -> $t {
+                self.rotor().mag()
+            }
+
+            /// Normalize this motor, so that it represents a rigid body transformation.
+            ///
+            /// This both scales the rotational part to unit magnitude and removes any drift in the
+            /// translational part (e.g. after summing or interpolating motors component-wise) which would
+            /// otherwise make the motor not represent a rigid body transformation.
+            #[inline]
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+
+            #[inline]
+            pub fn normalized(&self) -> Self {
+                // with `M * M.reversed() == a + b * e0123`, the normalized motor is `M * (a + b * e0123)^(-1/2)`,
+                // where `(a + b * e0123)^(-1/2) == 1 / sqrt(a) - b / (2 * a * sqrt(a)) * e0123`.
+                let mag = self.mag();
+                let inv_mag = $t::from(1.0) / mag;
+                let half_b = self.s.mul_add(self.e0123, -self.rotor_direction().dot(self.ideal()));
+                let y = -half_b * inv_mag * inv_mag * inv_mag;
+                Self::new(
+                    self.s * inv_mag,
+                    self.e23 * inv_mag,
+                    self.e31 * inv_mag,
+                    self.e12 * inv_mag,
+                    self.e01.mul_add(inv_mag, -(self.e23 * y)),
+                    self.e02.mul_add(inv_mag, -(self.e31 * y)),
+                    self.e03.mul_add(inv_mag, -(self.e12 * y)),
+                    self.e0123.mul_add(inv_mag, self.s * y),
+                )
+            }
+
+            /// The Euclidean bivector part of this motor, as the vector `(e23, e31, e12)`.
+            #[inline]
+            fn rotor_direction(&self) -> $vt {
+                $vt::new(self.e23, self.e31, self.e12)
+            }
+
+            /// The ideal bivector part of this motor, as the vector `(e01, e02, e03)`.
+            #[inline]
+            fn ideal(&self) -> $vt {
+                $vt::new(self.e01, self.e02, self.e03)
+            }
+
+            /// The dot product of the rotational parts of this and another motor.
+            #[inline]
+            pub fn dot(&self, other: Self) -> $t {
+                self.rotor().dot(other.rotor())
+            }
+
+            /// Transform a point, assuming this motor is normalized.
+            #[inline]
+            pub fn transform_point(&self, point: $pn) -> $pn {
+                let p = self.rotor() * $vt::new(point.e032, point.e013, point.e021) + self.translation() * point.e123;
+                $pn::new(p.x, p.y, p.z, point.e123)
+            }
+
+            /// Transform a position, assuming this motor is normalized.
+            #[inline]
+            pub fn transform_vec(&self, vec: $vt) -> $vt {
+                self.rotor() * vec + self.translation()
+            }
+
+            /// Transform a line, assuming this motor is normalized.
+            #[inline]
+            pub fn transform_line(&self, line: $ln) -> $ln {
+                let rotor = self.rotor();
+                let u = rotor * line.direction();
+                $ln::from_direction_moment(u, rotor * line.moment() + self.translation().cross(u))
+            }
+
+            /// Transform a plane, assuming this motor is normalized.
+            #[inline]
+            pub fn transform_plane(&self, plane: $pln) -> $pln {
+                let n = self.rotor() * plane.normal();
+                $pln::new(n.x, n.y, n.z, plane.e0 - n.dot(self.translation()))
+            }
+        }
+
+        impl From<$isot> for $mn {
+            #[inline]
+            fn from(iso: $isot) -> Self {
+                Self::from_isometry(iso)
+            }
+        }
+
+        impl From<$mn> for $isot {
+            #[inline]
+            fn from(motor: $mn) -> Self {
+                motor.into_isometry()
+            }
+        }
+
+        impl From<$rt> for $mn {
+            #[inline]
+            fn from(rotor: $rt) -> Self {
+                Self::from_rotor(rotor)
+            }
+        }
+
+        impl EqualsEps for $pn {
+            fn eq_eps(self, other: Self) -> bool {
+                self.e032.eq_eps(other.e032)
+                    && self.e013.eq_eps(other.e013)
+                    && self.e021.eq_eps(other.e021)
+                    && self.e123.eq_eps(other.e123)
+            }
+        }
+
+        impl EqualsEps for $ln {
+            fn eq_eps(self, other: Self) -> bool {
+                self.direction().eq_eps(other.direction()) && self.moment().eq_eps(other.moment())
+            }
+        }
+
+        impl EqualsEps for $pln {
+            fn eq_eps(self, other: Self) -> bool {
+                self.normal().eq_eps(other.normal()) && self.e0.eq_eps(other.e0)
+            }
+        }
+
+        impl EqualsEps for $mn {
+            fn eq_eps(self, other: Self) -> bool {
+                self.rotor().eq_eps(other.rotor())
+                    && self.e01.eq_eps(other.e01)
+                    && self.e02.eq_eps(other.e02)
+                    && self.e03.eq_eps(other.e03)
+                    && self.e0123.eq_eps(other.e0123)
+            }
+        }
+
+        /// The composition of two motors, i.e. their geometric product.
+        ///
+        /// `a * b` results in a motor that first applies `b` and then `a`.
+        impl Mul for $mn {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(
+                    self.s.mul_add(rhs.s, (-self.e23).mul_add(rhs.e23, (-self.e31).mul_add(rhs.e31, -(self.e12 * rhs.e12)))),
+                    self.s.mul_add(rhs.e23, self.e23.mul_add(rhs.s, self.e12.mul_add(rhs.e31, -(self.e31 * rhs.e12)))),
+                    self.s.mul_add(rhs.e31, self.e23.mul_add(rhs.e12, self.e31.mul_add(rhs.s, -(self.e12 * rhs.e23)))),
+                    self.s.mul_add(rhs.e12, self.e31.mul_add(rhs.e23, self.e12.mul_add(rhs.s, -(self.e23 * rhs.e31)))),
+                    self.s.mul_add(rhs.e01, self.e12.mul_add(rhs.e02, self.e01.mul_add(rhs.s, self.e03.mul_add(rhs.e31, (-self.e23).mul_add(rhs.e0123, (-self.e31).mul_add(rhs.e03, (-self.e02).mul_add(rhs.e12, -(self.e0123 * rhs.e23)))))))),
+                    self.s.mul_add(rhs.e02, self.e23.mul_add(rhs.e03, self.e01.mul_add(rhs.e12, self.e02.mul_add(rhs.s, (-self.e31).mul_add(rhs.e0123, (-self.e12).mul_add(rhs.e01, (-self.e03).mul_add(rhs.e23, -(self.e0123 * rhs.e31)))))))),
+                    self.s.mul_add(rhs.e03, self.e31.mul_add(rhs.e01, self.e02.mul_add(rhs.e23, self.e03.mul_add(rhs.s, (-self.e23).mul_add(rhs.e02, (-self.e12).mul_add(rhs.e0123, (-self.e01).mul_add(rhs.e31, -(self.e0123 * rhs.e12)))))))),
+                    self.s.mul_add(rhs.e0123, self.e23.mul_add(rhs.e01, self.e31.mul_add(rhs.e02, self.e12.mul_add(rhs.e03, self.e01.mul_add(rhs.e23, self.e02.mul_add(rhs.e31, self.e03.mul_add(rhs.e12, self.e0123 * rhs.s))))))),
+                )
+            }
+        }
+
+        impl Mul<$t> for $ln {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: $t) -> Self {
+                Self::from_direction_moment(self.direction() * rhs, self.moment() * rhs)
+            }
+        }
+
+        impl Add for $mn {
+            type Output = Self;
+            #[inline]
+            fn add(mut self, rhs: Self) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl AddAssign for $mn {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.s += rhs.s;
+                self.e23 += rhs.e23;
+                self.e31 += rhs.e31;
+                self.e12 += rhs.e12;
+                self.e01 += rhs.e01;
+                self.e02 += rhs.e02;
+                self.e03 += rhs.e03;
+                self.e0123 += rhs.e0123;
+            }
+        }
+
+        impl Mul<$t> for $mn {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: $t) -> Self {
+                Self::new(
+                    self.s * rhs,
+                    self.e23 * rhs,
+                    self.e31 * rhs,
+                    self.e12 * rhs,
+                    self.e01 * rhs,
+                    self.e02 * rhs,
+                    self.e03 * rhs,
+                    self.e0123 * rhs,
+                )
+            }
+        }
+
+        impl Neg for $mn {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                self * $t::from(-1.0)
+            }
+        }
+
+        impl Mul<$pn> for $mn {
+            type Output = $pn;
+            #[inline]
+            fn mul(self, point: $pn) -> $pn {
+                self.transform_point(point)
+            }
+        }
+
+        impl Mul<$ln> for $mn {
+            type Output = $ln;
+            #[inline]
+            fn mul(self, line: $ln) -> $ln {
+                self.transform_line(line)
+            }
+        }
+
+        impl Mul<$pln> for $mn {
+            type Output = $pln;
+            #[inline]
+            fn mul(self, plane: $pln) -> $pln {
+                self.transform_plane(plane)
+            }
+        }
+
+        impl Mul<$vt> for $mn {
+            type Output = $vt;
+            #[inline]
+            fn mul(self, vec: $vt) -> $vt {
+                self.transform_vec(vec)
+            }
+        }
+        )+
+    }
+}
+
+pgas!(
+    Point3, Line3, Plane3, Motor3 => (Vec3, Bivec3, Rotor3, Isometry3, f32),
+    WPoint3, WLine3, WPlane3, WMotor3 => (Wec3, WBivec3, WRotor3, WIsometry3, f32x4),
+    WPoint3x8, WLine3x8, WPlane3x8, WMotor3x8 => (Wec3x8, WBivec3x8, WRotor3x8, WIsometry3x8, f32x8),
+    DPoint3, DLine3, DPlane3, DMotor3 => (DVec3, DBivec3, DRotor3, DIsometry3, f64),
+    DWPoint3, DWLine3, DWPlane3, DWMotor3 => (DWec3, DWBivec3, DWRotor3, DWIsometry3, f64x4)
+);
+
+macro_rules! impl_motor_interp {
+    ($($mn:ident, $ln:ident => $t:ident),+) => {
+        $(impl $ln {
+            /// The exponential of this line (i.e. bivector), which is a normalized motor.
+            ///
+            /// This is the inverse of `log` on the corresponding motor. For a normalized line `l`,
+            /// `(l * (-angle / 2.0)).exp()` rotates by `angle` around `l`, the same as `from_screw(l, angle, 0.0)`.
+            #[inline]
+            pub fn exp(&self) -> $mn {
+                let u = self.direction();
+                let m = self.moment();
+                let angle = u.mag();
+                let (sin, cos) = angle.sin_cos();
+                // sin(x) / x approaches 1, and (cos(x) - sin(x) / x) / x^2 approaches -1/3 as x approaches 0
+                let (sinc, c) = if angle > 1e-3 {
+                    let sinc = sin / angle;
+                    (sinc, (cos - sinc) / (angle * angle))
+                } else {
+                    (1.0, angle.mul_add(angle / 30.0, -1.0 / 3.0))
+                };
+                let k = u.dot(m);
+                let rot = u * sinc;
+                let ideal = m * sinc + u * (k * c);
+                $mn::new(cos, rot.x, rot.y, rot.z, ideal.x, ideal.y, ideal.z, k * sinc)
+            }
+        }
+
+        impl $mn {
+            /// The logarithm of this motor, which must be normalized. This is the inverse of `exp` on the
+            /// corresponding line, and the rotational part of the result has a magnitude of at most pi.
+            #[inline]
+            pub fn log(&self) -> $ln {
+                let rot = self.rotor_direction();
+                let sin = rot.mag();
+                let angle = sin.atan2(self.s);
+                // angle / sin approaches 1 / cos as sin approaches 0
+                let scale = if sin > 1e-6 { angle / sin } else { 1.0 / self.s };
+                let c = if angle > 1e-3 {
+                    (self.s - sin / angle) / (angle * angle)
+                } else {
+                    angle.mul_add(angle / 30.0, -1.0 / 3.0)
+                };
+                let u = rot * scale;
+                let k = self.e0123 * scale;
+                $ln::from_direction_moment(u, (self.ideal() - u * (k * c)) * scale)
+            }
+
+            /// Screw linear interpolation (ScLERP) between this and another motor, both of which must be normalized.
+            ///
+            /// The result moves along the screw motion from `self` to `end`, i.e. it rotates around and translates
+            /// along a single fixed axis at constant speeds. This is the rigid body equivalent of `slerp`, and similarly
+            /// takes the shortest path, i.e. `end` is negated if its rotational part is further from `self` than its negation.
+            #[inline]
+            pub fn sclerp(&self, end: Self, t: $t) -> Self {
+                let end = if self.dot(end) < 0.0 { -end } else { end };
+                *self * ((self.reversed() * end).log() * t).exp()
+            }
+
+            /// Dual quaternion linear blending (DLB) of a set of normalized motors with the given weights, as is
+            /// commonly used for skinning. The weights should sum to one.
+            ///
+            /// This is a weighted sum of the motors followed by a normalization, which is much cheaper than
+            /// repeated `sclerp` and approximates it well. Each motor is negated if needed to be on the same side
+            /// as the first one, so that bones with the same rotation cannot cancel each other out.
+            ///
+            /// Panics if `motors` is empty, or if `motors` and `weights` have different lengths.
+            pub fn blend(motors: &[Self], weights: &[$t]) -> Self {
+                assert_eq!(motors.len(), weights.len());
+                let first = motors[0];
+                let mut sum = first * weights[0];
+                for (motor, weight) in motors.iter().zip(weights).skip(1) {
+                    let w = if first.dot(*motor) < 0.0 { -*weight } else { *weight };
+                    sum += *motor * w;
+                }
+                sum.normalized()
+            }
+        })+
+    };
+}
+
+impl_motor_interp!(Motor3, Line3 => f32, DMotor3, DLine3 => f64);
+
+macro_rules! impl_wide_motor_interp {
+    ($($mn:ident, $ln:ident => $t:ident),+) => {
+        $(impl $ln {
+            /// The exponential of this line (i.e. bivector), which is a normalized motor.
+            ///
+            /// This is the inverse of `log` on the corresponding motor. For a normalized line `l`,
+            /// `(l * (-angle / 2.0)).exp()` rotates by `angle` around `l`, the same as `from_screw(l, angle, 0.0)`.
+            #[inline]
+            pub fn exp(&self) -> $mn {
+                let u = self.direction();
+                let m = self.moment();
+                let angle = u.mag();
+                let (sin, cos) = angle.sin_cos();
+                let small = angle.cmp_lt($t::from(1e-3));
+                let sinc = small.blend($t::from(1.0), sin / angle);
+                let c = small.blend(
+                    angle.mul_add(angle / $t::from(30.0), $t::from(-1.0 / 3.0)),
+                    (cos - sinc) / (angle * angle),
+                );
+                let k = u.dot(m);
+                let rot = u * sinc;
+                let ideal = m * sinc + u * (k * c);
+                $mn::new(cos, rot.x, rot.y, rot.z, ideal.x, ideal.y, ideal.z, k * sinc)
+            }
+        }
+
+        impl $mn {
+            /// The logarithm of this motor, which must be normalized. This is the inverse of `exp` on the
+            /// corresponding line, and the rotational part of the result has a magnitude of at most pi.
+            #[inline]
+            pub fn log(&self) -> $ln {
+                let rot = self.rotor_direction();
+                let sin = rot.mag();
+                let angle = sin.atan2(self.s);
+                let scale = sin.cmp_gt($t::from(1e-6)).blend(angle / sin, $t::from(1.0) / self.s);
+                let c = angle.cmp_lt($t::from(1e-3)).blend(
+                    angle.mul_add(angle / $t::from(30.0), $t::from(-1.0 / 3.0)),
+                    (self.s - sin / angle) / (angle * angle),
+                );
+                let u = rot * scale;
+                let k = self.e0123 * scale;
+                $ln::from_direction_moment(u, (self.ideal() - u * (k * c)) * scale)
+            }
+
+            /// Screw linear interpolation (ScLERP) between this and another motor, both of which must be normalized.
+            ///
+            /// The result moves along the screw motion from `self` to `end`, i.e. it rotates around and translates
+            /// along a single fixed axis at constant speeds. This is the rigid body equivalent of `slerp`, and similarly
+            /// takes the shortest path, i.e. `end` is negated if its rotational part is further from `self` than its negation.
+            #[inline]
+            pub fn sclerp(&self, end: Self, t: $t) -> Self {
+                let end = <Self as Lanes>::merge(self.dot(end).cmp_lt($t::from(0.0)), -end, end);
+                *self * ((self.reversed() * end).log() * t).exp()
+            }
+
+            /// Dual quaternion linear blending (DLB) of a set of normalized motors with the given weights, as is
+            /// commonly used for skinning. The weights should sum to one.
+            ///
+            /// This is a weighted sum of the motors followed by a normalization, which is much cheaper than
+            /// repeated `sclerp` and approximates it well. Each motor is negated if needed to be on the same side
+            /// as the first one, so that bones with the same rotation cannot cancel each other out.
+            ///
+            /// Panics if `motors` is empty, or if `motors` and `weights` have different lengths.
+            pub fn blend(motors: &[Self], weights: &[$t]) -> Self {
+                assert_eq!(motors.len(), weights.len());
+                let first = motors[0];
+                let mut sum = first * weights[0];
+                for (motor, weight) in motors.iter().zip(weights).skip(1) {
+                    let w = first.dot(*motor).cmp_lt($t::from(0.0)).blend(-*weight, *weight);
+                    sum += *motor * w;
+                }
+                sum.normalized()
+            }
+        })+
+    };
+}
+
+impl_wide_motor_interp!(
+    WMotor3, WLine3 => f32x4,
+    WMotor3x8, WLine3x8 => f32x8,
+    DWMotor3, DWLine3 => f64x4
+);
+
+/// Convert from the `geometry` plane representation, where points on the plane satisfy `normal.dot(p) == bias`.
+impl From<Plane> for Plane3 {
+    #[inline]
+    fn from(plane: Plane) -> Self {
+        Self::new(plane.normal.x, plane.normal.y, plane.normal.z, -plane.bias)
+    }
+}
+
+/// Convert into the `geometry` plane representation, where points on the plane satisfy `normal.dot(p) == bias`.
+impl From<Plane3> for Plane {
+    #[inline]
+    fn from(plane: Plane3) -> Self {
+        Plane::new(plane.normal(), -plane.e0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn join_meet() {
+        let a = Point3::from(Vec3::new(1.0, 0.0, 0.0));
+        let b = Point3::from(Vec3::new(1.0, 2.0, 0.0));
+        let c = Point3::from(Vec3::new(1.0, 0.0, 3.0));
+        let line = a.join(b);
+        assert!(line.normalized().direction().eq_eps(Vec3::unit_y()));
+        assert!(line.closest_point_to_origin().eq_eps(Vec3::unit_x()));
+
+        let plane = line.join(c).normalized();
+        assert!(plane.eq_eps(Plane3::from_point_normal(Vec3::unit_x(), Vec3::unit_x())));
+        assert!(plane.signed_distance(Vec3::new(3.0, 1.0, 1.0)).eq_eps(2.0));
+
+        let floor = Plane3::from_point_normal(Vec3::new(0.0, 0.0, 2.0), Vec3::unit_z());
+        let edge = plane.meet(floor);
+        assert!(edge.normalized().direction().eq_eps(-Vec3::unit_y()));
+        let corner = edge.meet(Plane3::from_point_normal(Vec3::zero(), Vec3::unit_y()));
+        assert!(corner.into_vec().eq_eps(Vec3::new(1.0, 0.0, 2.0)));
+    }
+
+    #[test]
+    fn projections() {
+        let p = Point3::from(Vec3::new(1.0, 2.0, 3.0));
+        let plane = Plane3::new(0.0, 0.0, 2.0, -2.0);
+        assert!(p.project_onto_plane(plane).into_vec().eq_eps(Vec3::new(1.0, 2.0, 1.0)));
+
+        let line = Line3::from_point_direction(Vec3::new(0.0, 1.0, 0.0), Vec3::new(2.0, 0.0, 0.0));
+        assert!(p.project_onto_line(line).into_vec().eq_eps(Vec3::new(1.0, 1.0, 0.0)));
+
+        let projected = Line3::from_point_direction(Vec3::new(0.0, 0.0, 5.0), Vec3::new(1.0, 1.0, 1.0)).project_onto_plane(plane);
+        let expected = Line3::from_point_direction(Vec3::new(0.0, 0.0, 1.0), Vec3::new(1.0, 1.0, 0.0));
+        assert!(projected.normalized().eq_eps(expected.normalized()));
+    }
+
+    #[test]
+    fn motor_isometry_roundtrip() {
+        let rotation = Rotor3::from_euler_angles(0.3, -0.8, 1.2);
+        let translation = Vec3::new(1.0, -2.0, 3.0);
+        let iso = Isometry3::new(translation, rotation);
+        let motor = Motor3::from_isometry(iso);
+        assert!(motor.rotor().eq_eps(rotation));
+        assert!(motor.translation().eq_eps(translation));
+
+        let v = Vec3::new(0.5, 4.0, -1.0);
+        assert!((motor * v).eq_eps(iso * v));
+
+        let other = Isometry3::new(Vec3::new(-3.0, 0.5, 0.0), Rotor3::from_rotation_xz(0.7));
+        let composed = Motor3::from_isometry(iso) * Motor3::from_isometry(other);
+        assert!((composed * v).eq_eps((iso * other) * v));
+        assert!((composed.reversed() * (composed * v)).eq_eps(v));
+    }
+
+    #[test]
+    fn motor_transforms_elements() {
+        let motor = Motor3::from_isometry(Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_euler_angles(0.3, -0.8, 1.2)));
+        let a = Vec3::new(1.0, 0.0, 0.0);
+        let b = Vec3::new(0.0, 1.0, 0.5);
+        let c = Vec3::new(-1.0, 0.3, 2.0);
+        let line = Point3::from(a).join(Point3::from(b));
+        let plane = line.join(Point3::from(c));
+        let (ta, tb, tc) = (motor * a, motor * b, motor * c);
+        assert!((motor * line).eq_eps(Point3::from(ta).join(Point3::from(tb))));
+        assert!((motor * plane).eq_eps(Point3::from(ta).join(Point3::from(tb)).join(Point3::from(tc))));
+    }
+
+    #[test]
+    fn screw_motion() {
+        let axis = Line3::from_point_direction(Vec3::new(1.0, 0.0, 0.0), Vec3::unit_z());
+        let motor = Motor3::from_screw(axis, std::f32::consts::PI, 2.0);
+        assert!((motor * Vec3::zero()).eq_eps(Vec3::new(2.0, 0.0, 2.0)));
+        let quarter = Motor3::from_screw(axis, std::f32::consts::FRAC_PI_2, 0.0);
+        assert!((quarter * Vec3::zero()).eq_eps(Vec3::new(1.0, -1.0, 0.0)));
+    }
+
+    #[test]
+    fn wide_motor() {
+        let iso = Isometry3::new(Vec3::new(1.0, -2.0, 3.0), Rotor3::from_rotation_yz(0.4));
+        let v = Vec3::new(0.5, 4.0, -1.0);
+        let wide = WMotor3::from_isometry(WIsometry3::from_lanes(&[iso; 4]));
+        let res = wide * Wec3::splat(v);
+        assert!(res.extract(1).eq_eps(Motor3::from_isometry(iso) * v));
+    }
+
+    #[test]
+    fn motor_exp_log_roundtrip() {
+        let motor = Motor3::from_isometry(Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_euler_angles(0.3, -0.8, 1.2)));
+        assert!(motor.log().exp().eq_eps(motor));
+
+        let translation = Motor3::from_translation(Vec3::new(-1.0, 0.5, 2.0));
+        assert!(translation.log().exp().eq_eps(translation));
+
+        let axis = Line3::from_point_direction(Vec3::new(1.0, 0.0, 0.0), Vec3::unit_z());
+        let rotation = (axis * -0.5).exp();
+        assert!(rotation.eq_eps(Motor3::from_screw(axis, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn sclerp_follows_screw() {
+        let axis = Line3::from_point_direction(Vec3::new(1.0, 0.0, 0.0), Vec3::unit_z());
+        let start = Motor3::identity();
+        let end = Motor3::from_screw(axis, 2.0, 4.0);
+        assert!(start.sclerp(end, 0.0).eq_eps(start));
+        assert!(start.sclerp(end, 1.0).eq_eps(end));
+        assert!(start.sclerp(end, 0.5).eq_eps(Motor3::from_screw(axis, 1.0, 2.0)));
+        assert!(start.sclerp(-end, 0.5).eq_eps(Motor3::from_screw(axis, 1.0, 2.0)));
+    }
+
+    #[test]
+    fn blend_motors() {
+        let a = Motor3::from_isometry(Isometry3::new(Vec3::new(1.0, 0.0, 0.0), Rotor3::from_rotation_xy(0.4)));
+        let b = Motor3::from_isometry(Isometry3::new(Vec3::new(1.0, 0.0, 0.0), Rotor3::from_rotation_xy(1.2)));
+        let blended = Motor3::blend(&[a, -b], &[0.5, 0.5]);
+        assert!(blended.eq_eps(a.sclerp(b, 0.5)));
+
+        let c = Motor3::from_isometry(Isometry3::new(Vec3::new(0.0, 2.0, -1.0), Rotor3::from_rotation_yz(-0.7)));
+        let blended = Motor3::blend(&[a, b, c], &[0.2, 0.3, 0.5]);
+        let study = blended.s * blended.e0123 - blended.e23 * blended.e01 - blended.e31 * blended.e02 - blended.e12 * blended.e03;
+        assert!(blended.mag().eq_eps(1.0));
+        assert!(study.eq_eps(0.0));
+
+        let lanes = WMotor3::from_lanes(&[a, b, c, Motor3::identity()]);
+        let (weights, other_weights) = (f32x4::from([0.2, 0.3, 0.5, 1.0]), f32x4::from([0.8, 0.7, 0.5, 0.0]));
+        let wide = WMotor3::blend(&[lanes, WMotor3::from_lanes(&[-b; 4])], &[weights, other_weights]);
+        assert!(wide.extract(0).eq_eps(Motor3::blend(&[a, b], &[0.2, 0.8])));
+        assert!(wide.extract(3).eq_eps(Motor3::identity()));
+    }
+
+    #[test]
+    fn wide_sclerp() {
+        let start = Motor3::from_isometry(Isometry3::new(Vec3::new(0.0, 2.0, -1.0), Rotor3::from_rotation_yz(-0.7)));
+        let end = Motor3::from_isometry(Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_euler_angles(0.3, -0.8, 1.2)));
+        let wide = WMotor3::from_lanes(&[start; 4]).sclerp(WMotor3::from_lanes(&[end; 4]), f32x4::from([0.0, 0.25, 0.5, 1.0]));
+        assert!(wide.extract(2).eq_eps(start.sclerp(end, 0.5)));
+        assert!(wide.extract(3).eq_eps(end));
+    }
+}
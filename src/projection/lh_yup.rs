@@ -85,7 +85,7 @@ pub fn perspective_gl(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far:
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, (z_far + z_near) / nmf, 1.0),
+        Vec4::new(0.0, 0.0, -(z_far + z_near) / nmf, 1.0),
         Vec4::new(0.0, 0.0, 2.0 * z_near * z_far / nmf, 0.0),
     )
 }
@@ -108,7 +108,7 @@ pub fn perspective_dx(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far:
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, z_far / nmf, 1.0),
+        Vec4::new(0.0, 0.0, -z_far / nmf, 1.0),
         Vec4::new(0.0, 0.0, z_near * z_far / nmf, 0.0),
     )
 }
@@ -131,7 +131,7 @@ pub fn perspective_vk(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far:
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, -sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, z_far / nmf, 1.0),
+        Vec4::new(0.0, 0.0, -z_far / nmf, 1.0),
         Vec4::new(0.0, 0.0, z_near * z_far / nmf, 0.0),
     )
 }
@@ -157,7 +157,7 @@ pub fn perspective_infinite_z_gl(vertical_fov: f32, aspect_ratio: f32, z_near: f
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, -1.0, 1.0),
+        Vec4::new(0.0, 0.0, 1.0, 1.0),
         Vec4::new(0.0, 0.0, -2.0 * z_near, 0.0),
     )
 }
@@ -183,7 +183,7 @@ pub fn perspective_infinite_z_vk(vertical_fov: f32, aspect_ratio: f32, z_near: f
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, -sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, -1.0, 1.0),
+        Vec4::new(0.0, 0.0, 1.0, 1.0),
         Vec4::new(0.0, 0.0, -z_near, 0.0),
     )
 }
@@ -209,7 +209,7 @@ pub fn perspective_infinite_z_dx(vertical_fov: f32, aspect_ratio: f32, z_near: f
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, -1.0, 1.0),
+        Vec4::new(0.0, 0.0, 1.0, 1.0),
         Vec4::new(0.0, 0.0, -z_near, 0.0),
     )
 }
@@ -225,7 +225,7 @@ pub fn perspective_infinite_z_dx(vertical_fov: f32, aspect_ratio: f32, z_near: f
 ///
 /// This matrix is meant to be used when the source coordinate space is left-handed and y-up
 /// (the standard computer graphics coordinate space) and the destination coordinate space is
-/// left-handed and y-up with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+/// left-handed and y-up with Z (depth) clip extending from 1.0 (close) to 0.0 (far).
 ///
 /// **Note that in order for this to work properly with OpenGL, you'll need to use the `gl_arb_clip_control` extension
 /// and set the z clip from 0.0 to 1.0 rather than the default -1.0 to 1.0**
@@ -244,7 +244,7 @@ pub fn perspective_reversed_z_dx_gl(
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, -z_far / nmf - 1.0, 1.0),
+        Vec4::new(0.0, 0.0, z_near / nmf, 1.0),
         Vec4::new(0.0, 0.0, -z_near * z_far / nmf, 0.0),
     )
 }
@@ -260,7 +260,7 @@ pub fn perspective_reversed_z_dx_gl(
 ///
 /// This matrix is meant to be used when the source coordinate space is left-handed and y-up
 /// (the standard computer graphics coordinate space) and the destination coordinate space is
-/// right-handed and y-down with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+/// right-handed and y-down with Z (depth) clip extending from 1.0 (close) to 0.0 (far).
 #[inline]
 pub fn perspective_reversed_z_vk(
     vertical_fov: f32,
@@ -276,7 +276,7 @@ pub fn perspective_reversed_z_vk(
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, -sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, z_far / nmf, 1.0),
+        Vec4::new(0.0, 0.0, z_near / nmf, 1.0),
         Vec4::new(0.0, 0.0, -z_near * z_far / nmf, 0.0),
     )
 }
@@ -298,7 +298,7 @@ pub fn perspective_reversed_z_vk(
 ///
 /// This matrix is meant to be used when the source coordinate space is left-handed and y-up
 /// (the standard computer graphics coordinate space) and the destination coordinate space is
-/// left-handed and y-up with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+/// left-handed and y-up with Z (depth) clip extending from 1.0 (close) to 0.0 (far).
 ///
 /// **Note that in order for this to work properly with OpenGL, you'll need to use the `gl_arb_clip_control` extension
 /// and set the z clip from 0.0 to 1.0 rather than the default -1.0 to 1.0**
@@ -337,7 +337,7 @@ pub fn perspective_reversed_infinite_z_dx_gl(
 ///
 /// This matrix is meant to be used when the source coordinate space is left-handed and y-up
 /// (the standard computer graphics coordinate space) and the destination coordinate space is
-/// right-handed and y-down with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+/// right-handed and y-down with Z (depth) clip extending from 1.0 (close) to 0.0 (far).
 #[inline]
 pub fn perspective_reversed_infinite_z_vk(
     vertical_fov: f32,
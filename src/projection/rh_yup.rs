@@ -226,7 +226,7 @@ pub fn perspective_infinite_z_dx(vertical_fov: f32, aspect_ratio: f32, z_near: f
 ///
 /// This matrix is meant to be used when the source coordinate space is right-handed and y-up
 /// (the standard computer graphics coordinate space) and the destination coordinate space is
-/// left-handed and y-up with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+/// left-handed and y-up with Z (depth) clip extending from 1.0 (close) to 0.0 (far).
 ///
 /// **Note that in order for this to work properly with OpenGL, you'll need to use the `gl_arb_clip_control` extension
 /// and set the z clip from 0.0 to 1.0 rather than the default -1.0 to 1.0**
@@ -261,7 +261,7 @@ pub fn perspective_reversed_z_dx_gl(
 ///
 /// This matrix is meant to be used when the source coordinate space is right-handed and y-up
 /// (the standard computer graphics coordinate space) and the destination coordinate space is
-/// right-handed and y-down with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+/// right-handed and y-down with Z (depth) clip extending from 1.0 (close) to 0.0 (far).
 #[inline]
 pub fn perspective_reversed_z_vk(
     vertical_fov: f32,
@@ -277,7 +277,7 @@ pub fn perspective_reversed_z_vk(
     Mat4::new(
         Vec4::new(sx, 0.0, 0.0, 0.0),
         Vec4::new(0.0, -sy, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, z_far / nmf, -1.0),
+        Vec4::new(0.0, 0.0, -z_far / nmf - 1.0, -1.0),
         Vec4::new(0.0, 0.0, -z_near * z_far / nmf, 0.0),
     )
 }
@@ -299,7 +299,7 @@ pub fn perspective_reversed_z_vk(
 ///
 /// This matrix is meant to be used when the source coordinate space is right-handed and y-up
 /// (the standard computer graphics coordinate space) and the destination coordinate space is
-/// left-handed and y-up with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+/// left-handed and y-up with Z (depth) clip extending from 1.0 (close) to 0.0 (far).
 ///
 /// **Note that in order for this to work properly with OpenGL, you'll need to use the `gl_arb_clip_control` extension
 /// and set the z clip from 0.0 to 1.0 rather than the default -1.0 to 1.0**
@@ -338,7 +338,7 @@ pub fn perspective_reversed_infinite_z_dx_gl(
 ///
 /// This matrix is meant to be used when the source coordinate space is right-handed and y-up
 /// (the standard computer graphics coordinate space) and the destination coordinate space is
-/// right-handed and y-down with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+/// right-handed and y-down with Z (depth) clip extending from 1.0 (close) to 0.0 (far).
 #[inline]
 pub fn perspective_reversed_infinite_z_vk(
     vertical_fov: f32,
@@ -54,7 +54,7 @@
 use crate::util::*;
 use crate::*;
 
-use wide::f32x4;
+use wide::{f32x4, f32x8, f64x4};
 
 use std::alloc::Layout;
 use std::ops::*;
@@ -123,6 +123,13 @@ macro_rules! rotor2s {
                 Self::new(cos, $bt::new(-sin))
             }
 
+            /// The dot product of the two rotors, treated as 2-component vectors. For normalized rotors,
+            /// this is the cosine of half the angle between them.
+            #[inline]
+            pub fn dot(&self, rhs: Self) -> $t {
+                self.s.mul_add(rhs.s, self.bv.xy * rhs.bv.xy)
+            }
+
             #[inline]
             pub fn mag_sq(&self) -> $t {
                 self.s.mul_add(self.s, self.bv.mag_sq())
@@ -212,6 +219,14 @@ macro_rules! rotor2s {
                         s2_minus_bxy2))
             }
 
+            /// Construct a rotor from a rotation matrix. This is the inverse of `into_matrix`.
+            ///
+            /// Any scale or shear in `m` is ignored, i.e. the result is the rotation closest to `m`.
+            #[inline]
+            pub fn from_rotation_matrix(m: $mt) -> Self {
+                Self::from_angle((m.cols[0].y - m.cols[1].x).atan2(m.cols[0].x + m.cols[1].y))
+            }
+
             #[inline]
             pub fn layout() -> Layout {
                 Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
@@ -292,8 +307,8 @@ macro_rules! rotor2s {
         impl MulAssign<$t> for $rn {
             #[inline]
             fn mul_assign(&mut self, rhs: $t) {
-                self.s /= rhs;
-                self.bv /= rhs;
+                self.s *= rhs;
+                self.bv *= rhs;
             }
         }
 
@@ -317,7 +332,13 @@ macro_rules! rotor2s {
     }
 }
 
-rotor2s!(Rotor2 => (Mat2, Vec2, Bivec2, f32), WRotor2 => (Wat2, Wec2, WBivec2, f32x4));
+rotor2s!(
+    Rotor2 => (Mat2, Vec2, Bivec2, f32),
+    WRotor2 => (Wat2, Wec2, WBivec2, f32x4),
+    WRotor2x8 => (Wat2x8, Wec2x8, WBivec2x8, f32x8),
+    DRotor2 => (DMat2, DVec2, DBivec2, f64),
+    DWRotor2 => (DWat2, DWec2, DWBivec2, f64x4)
+);
 
 macro_rules! rotor3s {
     ($($rn:ident => ($mt:ident, $vt:ident, $bt:ident, $t:ident)),+) => {
@@ -405,6 +426,42 @@ macro_rules! rotor3s {
                     * Self::from_angle_plane(roll, $bt::unit_xy())
             }
 
+            /// Construct a rotor from a unit quaternion stored as `[x, y, z, w]`, which is the layout used by
+            /// most file formats (e.g. glTF) and physics engines.
+            ///
+            /// The quaternion is expected to follow the usual convention where it rotates a vector `v` by
+            /// `q * v * q^-1`, and a rotation by `angle` around a normalized `axis` is
+            /// `[axis * sin(angle / 2), cos(angle / 2)]`, so that positive angles rotate counterclockwise when
+            /// looking down the axis towards the origin (right-hand rule). The same quaternion therefore gives
+            /// the same rotation matrix regardless of whether the coordinate system is left- or right-handed.
+            ///
+            /// The mapping is `s = w`, `xy = -z`, `xz = y` and `yz = -x`; the signs come from the fact that e.g.
+            /// the xy plane is oriented from x to y, which is a rotation by a *negative* angle around +z under
+            /// this crate's conventions (see `from_angle_plane`).
+            #[inline]
+            pub fn from_quaternion_xyzw(q: [$t; 4]) -> Self {
+                Self::new(q[3], $bt::new(-q[2], q[1], -q[0]))
+            }
+
+            /// Convert this rotor into a unit quaternion stored as `[x, y, z, w]`. This is the inverse of
+            /// `from_quaternion_xyzw`, see there for the conventions used.
+            #[inline]
+            pub fn into_quaternion_xyzw(self) -> [$t; 4] {
+                [-self.bv.yz, self.bv.xz, -self.bv.xy, self.s]
+            }
+
+            /// The dot product of the two rotors, treated as 4-component vectors. For normalized rotors,
+            /// this is the cosine of half the angle between them.
+            #[inline]
+            pub fn dot(&self, rhs: Self) -> $t {
+                self.s.mul_add(
+                    rhs.s,
+                    self.bv
+                        .xy
+                        .mul_add(rhs.bv.xy, self.bv.xz.mul_add(rhs.bv.xz, self.bv.yz * rhs.bv.yz)),
+                )
+            }
+
             #[inline]
             pub fn mag_sq(&self) -> $t {
                 self.s.mul_add(self.s, self.bv.mag_sq())
@@ -650,8 +707,8 @@ macro_rules! rotor3s {
         impl MulAssign<$t> for $rn {
             #[inline]
             fn mul_assign(&mut self, rhs: $t) {
-                self.s /= rhs;
-                self.bv /= rhs;
+                self.s *= rhs;
+                self.bv *= rhs;
             }
         }
 
@@ -675,7 +732,769 @@ macro_rules! rotor3s {
     }
 }
 
-rotor3s!(Rotor3 => (Mat3, Vec3, Bivec3, f32), WRotor3 => (Wat3, Wec3, WBivec3, f32x4));
+rotor3s!(
+    Rotor3 => (Mat3, Vec3, Bivec3, f32),
+    WRotor3 => (Wat3, Wec3, WBivec3, f32x4),
+    WRotor3x8 => (Wat3x8, Wec3x8, WBivec3x8, f32x8),
+    DRotor3 => (DMat3, DVec3, DBivec3, f64),
+    DWRotor3 => (DWat3, DWec3, DWBivec3, f64x4)
+);
+
+macro_rules! rotor4s {
+    ($($rn:ident => ($mt:ident, $vt:ident, $bt:ident, $t:ident)),+) => {
+        $(
+        /// A Rotor in 4d space.
+        ///
+        /// In addition to the scalar and bivector parts of lower-dimensional rotors, a 4d rotor has a
+        /// *quadvector* (pseudoscalar) part, `xyzw`. This is zero for *simple* rotations (a rotation inside a
+        /// single plane, e.g. as created by `from_angle_plane`) but not in general, since composing
+        /// rotations in two completely orthogonal planes (a *double* rotation, e.g. in the xy and zw planes)
+        /// is possible in 4d.
+        ///
+        /// Please see the module level documentation for more information on rotors!
+        #[derive(Clone, Copy, Debug)]
+        #[repr(C)]
+        pub struct $rn {
+            pub s: $t,
+            pub bv: $bt,
+            pub xyzw: $t,
+        }
+
+        derive_default_identity!($rn);
+
+        impl $rn {
+            #[inline]
+            pub fn new(scalar: $t, bivector: $bt, quadvector: $t) -> Self {
+                Self {
+                    s: scalar,
+                    bv: bivector,
+                    xyzw: quadvector,
+                }
+            }
+
+            #[inline]
+            pub fn identity() -> Self {
+                Self {
+                    s: $t::from(1.0),
+                    bv: $bt::zero(),
+                    xyzw: $t::from(0.0),
+                }
+            }
+
+            /// Construct a Rotor that rotates one vector to another.
+            #[inline]
+            pub fn from_rotation_between(from: $vt, to: $vt) -> Self {
+                Self::new(
+                    $t::from(1.0) + to.dot(from),
+                    to.wedge(from),
+                    $t::from(0.0)).normalized()
+            }
+
+            /// Construct a rotor given a bivector which defines a plane and rotation orientation,
+            /// and a rotation angle.
+            ///
+            /// `plane` must be normalized!
+            #[inline]
+            pub fn from_angle_plane(angle: $t, plane: $bt) -> Self {
+                let half_angle = angle / $t::from(2.0);
+                let (sin, cos) = half_angle.sin_cos();
+                Self::new(cos, plane * -sin, $t::from(0.0))
+            }
+
+            /// Create new Rotor from a rotation in the xy plane.
+            #[inline]
+            pub fn from_rotation_xy(angle: $t) -> Self {
+                Self::from_angle_plane(angle, $bt::unit_xy())
+            }
+
+            /// Create new Rotor from a rotation in the xz plane.
+            #[inline]
+            pub fn from_rotation_xz(angle: $t) -> Self {
+                Self::from_angle_plane(angle, $bt::unit_xz())
+            }
+
+            /// Create new Rotor from a rotation in the xw plane.
+            #[inline]
+            pub fn from_rotation_xw(angle: $t) -> Self {
+                Self::from_angle_plane(angle, $bt::unit_xw())
+            }
+
+            /// Create new Rotor from a rotation in the yz plane.
+            #[inline]
+            pub fn from_rotation_yz(angle: $t) -> Self {
+                Self::from_angle_plane(angle, $bt::unit_yz())
+            }
+
+            /// Create new Rotor from a rotation in the yw plane.
+            #[inline]
+            pub fn from_rotation_yw(angle: $t) -> Self {
+                Self::from_angle_plane(angle, $bt::unit_yw())
+            }
+
+            /// Create new Rotor from a rotation in the zw plane.
+            #[inline]
+            pub fn from_rotation_zw(angle: $t) -> Self {
+                Self::from_angle_plane(angle, $bt::unit_zw())
+            }
+
+            #[inline]
+            pub fn mag_sq(&self) -> $t {
+                self.s.mul_add(self.s, self.xyzw.mul_add(self.xyzw, self.bv.mag_sq()))
+            }
+
+            #[inline]
+            pub fn mag(&self) -> $t {
+                self.mag_sq().sqrt()
+            }
+
+            #[inline]
+            pub fn normalize(&mut self) {
+                let mag = self.mag();
+                self.s /= mag;
+                self.bv /= mag;
+                self.xyzw /= mag;
+            }
+
+            #[inline]
+            pub fn normalized(&self) -> Self {
+                let mut s = *self;
+                s.normalize();
+                s
+            }
+
+            /// Reverses the order of the basis vectors in each component, which negates the bivector
+            /// part (the quadvector part is unchanged). For a normalized rotor, this is the inverse rotation.
+            #[inline]
+            pub fn reverse(&mut self) {
+                self.bv = -self.bv;
+            }
+
+            #[inline]
+            pub fn reversed(&self) -> Self {
+                let mut s = *self;
+                s.reverse();
+                s
+            }
+
+            /// Rotates a vector by this rotor.
+            ///
+            /// `self` *must* be normalized!
+            #[inline]
+            pub fn rotate_vec(self, vec: &mut $vt) {
+                let Self { s, bv, xyzw: q } = self;
+                let v = *vec;
+
+                // First, the geometric product `rotor * vec`, which has a vector and a trivector part.
+                let tx = s.mul_add(v.x, v.w.mul_add(bv.xw, v.y.mul_add(bv.xy, v.z * bv.xz)));
+                let ty = s.mul_add(v.y, v.w.mul_add(bv.yw, v.z.mul_add(bv.yz, -(v.x * bv.xy))));
+                let tz = s.mul_add(v.z, v.w.mul_add(bv.zw, (-v.x).mul_add(bv.xz, -(v.y * bv.yz))));
+                let tw = s.mul_add(v.w, (-v.x).mul_add(bv.xw, (-v.y).mul_add(bv.yw, -(v.z * bv.zw))));
+                let txyz = q.mul_add(v.w, v.x.mul_add(bv.yz, v.z.mul_add(bv.xy, -(v.y * bv.xz))));
+                let txyw = v.w.mul_add(bv.xy, v.x.mul_add(bv.yw, (-q).mul_add(v.z, -(v.y * bv.xw))));
+                let txzw = q.mul_add(v.y, v.w.mul_add(bv.xz, v.x.mul_add(bv.zw, -(v.z * bv.xw))));
+                let tyzw = v.w.mul_add(bv.yz, v.y.mul_add(bv.zw, (-q).mul_add(v.x, -(v.z * bv.yw))));
+
+                // Then, the vector part of the product of that with the reverse of the rotor.
+                vec.x = q.mul_add(tyzw, s.mul_add(tx, tw.mul_add(bv.xw, txyw.mul_add(bv.yw, txyz.mul_add(bv.yz, txzw.mul_add(bv.zw, ty.mul_add(bv.xy, tz * bv.xz)))))));
+                vec.y = s.mul_add(ty, tw.mul_add(bv.yw, tyzw.mul_add(bv.zw, tz.mul_add(bv.yz, (-q).mul_add(txzw, (-tx).mul_add(bv.xy, (-txyw).mul_add(bv.xw, -(txyz * bv.xz))))))));
+                vec.z = q.mul_add(txyw, s.mul_add(tz, tw.mul_add(bv.zw, txyz.mul_add(bv.xy, (-tx).mul_add(bv.xz, (-txzw).mul_add(bv.xw, (-ty).mul_add(bv.yz, -(tyzw * bv.yw))))))));
+                vec.w = s.mul_add(tw, txyw.mul_add(bv.xy, txzw.mul_add(bv.xz, tyzw.mul_add(bv.yz, (-q).mul_add(txyz, (-tx).mul_add(bv.xw, (-ty).mul_add(bv.yw, -(tz * bv.zw))))))));
+            }
+
+            #[inline]
+            pub fn into_matrix(self) -> $mt {
+                let Self { s, bv, xyzw: q } = self;
+                let two = $t::from(2.0);
+
+                let s2 = s * s;
+                let q2 = q * q;
+                let xy2 = bv.xy * bv.xy;
+                let xz2 = bv.xz * bv.xz;
+                let xw2 = bv.xw * bv.xw;
+                let yz2 = bv.yz * bv.yz;
+                let yw2 = bv.yw * bv.yw;
+                let zw2 = bv.zw * bv.zw;
+
+                let s_xy = s * bv.xy;
+                let s_xz = s * bv.xz;
+                let s_xw = s * bv.xw;
+                let s_yz = s * bv.yz;
+                let s_yw = s * bv.yw;
+                let s_zw = s * bv.zw;
+                let q_xy = q * bv.xy;
+                let q_xz = q * bv.xz;
+                let q_xw = q * bv.xw;
+                let q_yz = q * bv.yz;
+                let q_yw = q * bv.yw;
+                let q_zw = q * bv.zw;
+                let xy_xz = bv.xy * bv.xz;
+                let xy_xw = bv.xy * bv.xw;
+                let xy_yw = bv.xy * bv.yw;
+                let xy_yz = bv.xy * bv.yz;
+                let xz_xw = bv.xz * bv.xw;
+                let xz_yz = bv.xz * bv.yz;
+                let xz_zw = bv.xz * bv.zw;
+                let xw_yw = bv.xw * bv.yw;
+                let xw_zw = bv.xw * bv.zw;
+                let yz_zw = bv.yz * bv.zw;
+                let yw_zw = bv.yw * bv.zw;
+                let yz_yw = bv.yz * bv.yw;
+                let s2_q2 = s2 - q2;
+
+                $mt::new(
+                    $vt::new(
+                        s2_q2 - xy2 - xz2 - xw2 + yz2 + yw2 + zw2,
+                        -two * (q_zw + s_xy + xw_yw + xz_yz),
+                        two * (q_yw - s_xz - xw_zw + xy_yz),
+                        -two * (q_yz + s_xw - xy_yw - xz_zw)),
+                    $vt::new(
+                        two * (q_zw + s_xy - xw_yw - xz_yz),
+                        s2_q2 - xy2 + xz2 + xw2 - yz2 - yw2 + zw2,
+                        -two * (q_xw + s_yz + xy_xz + yw_zw),
+                        two * (q_xz - s_yw - xy_xw + yz_zw)),
+                    $vt::new(
+                        -two * (q_yw - s_xz + xw_zw - xy_yz),
+                        two * (q_xw + s_yz - xy_xz - yw_zw),
+                        s2_q2 + xy2 - xz2 + xw2 - yz2 + yw2 - zw2,
+                        -two * (q_xy + s_zw + xz_xw + yz_yw)),
+                    $vt::new(
+                        two * (q_yz + s_xw + xy_yw + xz_zw),
+                        -two * (q_xz - s_yw + xy_xw - yz_zw),
+                        two * (q_xy + s_zw - xz_xw - yz_yw),
+                        s2_q2 + xy2 + xz2 - xw2 + yz2 - yw2 - zw2)
+                )
+            }
+
+            #[inline]
+            pub fn layout() -> Layout {
+                Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+            }
+        }
+
+        impl From<$rn> for $mt {
+            #[inline]
+            fn from(rotor: $rn) -> $mt {
+                rotor.into_matrix()
+            }
+        }
+
+        impl EqualsEps for $rn {
+            #[inline]
+            fn eq_eps(self, other: Self) -> bool {
+                self.s.eq_eps(other.s) && self.bv.eq_eps(other.bv) && self.xyzw.eq_eps(other.xyzw)
+            }
+        }
+
+        /// The composition of `self` with `q`, i.e. `self * q` gives the rotation as though
+        /// you first perform `q` and then `self`.
+        impl Mul for $rn {
+            type Output = Self;
+            #[inline]
+            fn mul(self, q: Self) -> Self {
+                let (a_s, a, aq) = (self.s, self.bv, self.xyzw);
+                let (b_s, b, bq) = (q.s, q.bv, q.xyzw);
+                Self {
+                    s: aq.mul_add(bq, a_s.mul_add(b_s, (-a.xw).mul_add(b.xw, (-a.xy).mul_add(b.xy, (-a.xz).mul_add(b.xz, (-a.yw).mul_add(b.yw, (-a.yz).mul_add(b.yz, -(a.zw * b.zw)))))))),
+                    bv: $bt {
+                        xy: a_s.mul_add(b.xy, a.xy.mul_add(b_s, a.yw.mul_add(b.xw, a.yz.mul_add(b.xz, (-aq).mul_add(b.zw, (-a.xw).mul_add(b.yw, (-a.xz).mul_add(b.yz, -(a.zw * bq)))))))),
+                        xz: aq.mul_add(b.yw, a_s.mul_add(b.xz, a.xy.mul_add(b.yz, a.xz.mul_add(b_s, a.yw.mul_add(bq, a.zw.mul_add(b.xw, (-a.xw).mul_add(b.zw, -(a.yz * b.xy)))))))),
+                        xw: a_s.mul_add(b.xw, a.xw.mul_add(b_s, a.xy.mul_add(b.yw, a.xz.mul_add(b.zw, (-aq).mul_add(b.yz, (-a.yw).mul_add(b.xy, (-a.yz).mul_add(bq, -(a.zw * b.xz)))))))),
+                        yz: a_s.mul_add(b.yz, a.xz.mul_add(b.xy, a.yz.mul_add(b_s, a.zw.mul_add(b.yw, (-aq).mul_add(b.xw, (-a.xw).mul_add(bq, (-a.xy).mul_add(b.xz, -(a.yw * b.zw)))))))),
+                        yw: aq.mul_add(b.xz, a_s.mul_add(b.yw, a.xw.mul_add(b.xy, a.xz.mul_add(bq, a.yw.mul_add(b_s, a.yz.mul_add(b.zw, (-a.xy).mul_add(b.xw, -(a.zw * b.yz)))))))),
+                        zw: a_s.mul_add(b.zw, a.xw.mul_add(b.xz, a.yw.mul_add(b.yz, a.zw.mul_add(b_s, (-aq).mul_add(b.xy, (-a.xy).mul_add(bq, (-a.xz).mul_add(b.xw, -(a.yz * b.yw)))))))),
+                    },
+                    xyzw: aq.mul_add(b_s, a_s.mul_add(bq, a.xw.mul_add(b.yz, a.xy.mul_add(b.zw, a.yz.mul_add(b.xw, a.zw.mul_add(b.xy, (-a.xz).mul_add(b.yw, -(a.yw * b.xz)))))))),
+                }
+            }
+        }
+
+        impl AddAssign for $rn {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.s += rhs.s;
+                self.bv += rhs.bv;
+                self.xyzw += rhs.xyzw;
+            }
+        }
+
+        impl Add for $rn {
+            type Output = Self;
+            #[inline]
+            fn add(mut self, rhs: Self) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl SubAssign for $rn {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.s -= rhs.s;
+                self.bv -= rhs.bv;
+                self.xyzw -= rhs.xyzw;
+            }
+        }
+
+        impl Sub for $rn {
+            type Output = Self;
+            #[inline]
+            fn sub(mut self, rhs: Self) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl Mul<$vt> for $rn {
+            type Output = $vt;
+            #[inline]
+            fn mul(self, mut rhs: $vt) -> $vt {
+                self.rotate_vec(&mut rhs);
+                rhs
+            }
+        }
+
+        impl MulAssign<$t> for $rn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                self.s *= rhs;
+                self.bv *= rhs;
+                self.xyzw *= rhs;
+            }
+        }
+
+        impl Mul<$t> for $rn {
+            type Output = Self;
+            #[inline]
+            fn mul(mut self, rhs: $t) -> Self {
+                self *= rhs;
+                self
+            }
+        }
+
+        impl Mul<$rn> for $t {
+            type Output = $rn;
+            #[inline]
+            fn mul(self, rotor: $rn) -> $rn {
+                rotor * self
+            }
+        }
+        )+
+    }
+}
+
+rotor4s!(
+    Rotor4 => (Mat4, Vec4, Bivec4, f32),
+    WRotor4 => (Wat4, Wec4, WBivec4, f32x4),
+    WRotor4x8 => (Wat4x8, Wec4x8, WBivec4x8, f32x8),
+    DRotor4 => (DMat4, DVec4, DBivec4, f64),
+    DWRotor4 => (DWat4, DWec4, DWBivec4, f64x4)
+);
+
+macro_rules! impl_exp_log {
+    ($($rt:ident, $r2t:ident => ($bt:ident, $b2t:ident, $t:ident)),+) => {
+        $(impl $b2t {
+            /// The exponential of this bivector, which is the normalized rotor `cos(xy) + sin(xy) * e12`.
+            ///
+            /// This is the inverse of `Rotor2::log`, and `Rotor2::from_angle(angle)` is the same as
+            /// `Bivec2::new(-angle / 2.0).exp()`.
+            #[inline]
+            pub fn exp(&self) -> $r2t {
+                let (sin, cos) = self.xy.sin_cos();
+                $r2t::new(cos, $b2t::new(sin))
+            }
+        }
+
+        impl $r2t {
+            /// The logarithm of this rotor, which must be normalized. This is the inverse of `Bivec2::exp`,
+            /// and the result has a magnitude of at most pi.
+            #[inline]
+            pub fn log(&self) -> $b2t {
+                $b2t::new(self.bv.xy.atan2(self.s))
+            }
+        }
+
+        impl $bt {
+            /// The exponential of this bivector, which is the normalized rotor
+            /// `cos(|B|) + B / |B| * sin(|B|)`.
+            ///
+            /// This is the inverse of `Rotor3::log`, and `Rotor3::from_angle_plane(angle, plane)` is the same as
+            /// `(plane * (-angle / 2.0)).exp()`. This means that a bivector representing an angular
+            /// velocity `w` can be integrated over a timestep `dt` with `(w * (-dt / 2.0)).exp() * rotor`.
+            #[inline]
+            pub fn exp(&self) -> $rt {
+                let angle = self.mag();
+                let (sin, cos) = angle.sin_cos();
+                // sin(x) / x approaches 1 as x approaches 0
+                let scale = if angle > 1e-6 { sin / angle } else { 1.0 };
+                $rt::new(cos, *self * scale)
+            }
+        }
+
+        impl $rt {
+            /// The logarithm of this rotor, which must be normalized. This is the inverse of `Bivec3::exp`,
+            /// and the result has a magnitude of at most pi.
+            ///
+            /// The plane of the result is undefined for a rotor equal to `-1` (which represents the same
+            /// rotation as the identity rotor).
+            #[inline]
+            pub fn log(&self) -> $bt {
+                let sin = self.bv.mag();
+                let angle = sin.atan2(self.s);
+                // angle / sin approaches 1 / cos as sin approaches 0
+                let scale = if sin > 1e-6 { angle / sin } else { 1.0 / self.s };
+                self.bv * scale
+            }
+        })+
+    };
+}
+
+impl_exp_log!(
+    Rotor3, Rotor2 => (Bivec3, Bivec2, f32),
+    DRotor3, DRotor2 => (DBivec3, DBivec2, f64)
+);
+
+macro_rules! impl_wide_exp_log {
+    ($($rt:ident, $r2t:ident => ($bt:ident, $b2t:ident, $t:ident)),+) => {
+        $(impl $b2t {
+            /// The exponential of this bivector, which is the normalized rotor `cos(xy) + sin(xy) * e12`.
+            ///
+            /// This is the inverse of `log` on the corresponding rotor.
+            #[inline]
+            pub fn exp(&self) -> $r2t {
+                let (sin, cos) = self.xy.sin_cos();
+                $r2t::new(cos, $b2t::new(sin))
+            }
+        }
+
+        impl $r2t {
+            /// The logarithm of this rotor, which must be normalized. This is the inverse of `exp` on the
+            /// corresponding bivector, and the result has a magnitude of at most pi.
+            #[inline]
+            pub fn log(&self) -> $b2t {
+                $b2t::new(self.bv.xy.atan2(self.s))
+            }
+        }
+
+        impl $bt {
+            /// The exponential of this bivector, which is the normalized rotor
+            /// `cos(|B|) + B / |B| * sin(|B|)`.
+            ///
+            /// This is the inverse of `log` on the corresponding rotor.
+            #[inline]
+            pub fn exp(&self) -> $rt {
+                let angle = self.mag();
+                let (sin, cos) = angle.sin_cos();
+                let scale = angle.cmp_gt($t::from(1e-6)).blend(sin / angle, $t::from(1.0));
+                $rt::new(cos, *self * scale)
+            }
+        }
+
+        impl $rt {
+            /// The logarithm of this rotor, which must be normalized. This is the inverse of `exp` on the
+            /// corresponding bivector, and the result has a magnitude of at most pi.
+            ///
+            /// The plane of the result is undefined in lanes where the rotor is equal to `-1`.
+            #[inline]
+            pub fn log(&self) -> $bt {
+                let sin = self.bv.mag();
+                let angle = sin.atan2(self.s);
+                let scale = sin.cmp_gt($t::from(1e-6)).blend(angle / sin, $t::from(1.0) / self.s);
+                self.bv * scale
+            }
+        })+
+    };
+}
+
+impl_wide_exp_log!(
+    WRotor3, WRotor2 => (WBivec3, WBivec2, f32x4),
+    WRotor3x8, WRotor2x8 => (WBivec3x8, WBivec2x8, f32x8),
+    DWRotor3, DWRotor2 => (DWBivec3, DWBivec2, f64x4)
+);
+
+macro_rules! impl_into_angles {
+    ($($rt:ident, $r2t:ident => ($bt:ident, $b2t:ident, $vt:ident, $t:ident, $pi:expr)),+) => {
+        $(impl $r2t {
+            /// The angle of rotation of this rotor, which must be normalized. This is the inverse of
+            /// `Rotor2::from_angle`, and the result is in the range `[-2pi, 2pi]`.
+            #[inline]
+            pub fn into_angle(self) -> $t {
+                self.bv.xy.atan2(self.s) * -2.0
+            }
+
+            /// The bivector describing the plane, orientation and angle of rotation of this rotor, which
+            /// must be normalized. This is the inverse of `Rotor2::from_angle_plane`.
+            #[inline]
+            pub fn into_angle_plane(self) -> $b2t {
+                $b2t::new(self.into_angle())
+            }
+        }
+
+        impl $rt {
+            /// The angle and plane of rotation of this rotor, which must be normalized. This is the inverse
+            /// of `Rotor3::from_angle_plane`.
+            ///
+            /// The angle is in the range `[0, 2pi]`, so that the returned values reconstruct exactly this
+            /// rotor (rather than its negation, which represents the same rotation). The plane is
+            /// normalized, and is arbitrarily chosen as the xy plane when the angle is 0 or 2pi.
+            #[inline]
+            pub fn into_angle_plane(self) -> ($t, $bt) {
+                let sin = self.bv.mag();
+                let angle = sin.atan2(self.s) * 2.0;
+                let plane = if sin > 1e-6 { self.bv * (-1.0 / sin) } else { $bt::unit_xy() };
+                (angle, plane)
+            }
+
+            /// The axis and angle of rotation of this rotor, which must be normalized. The axis is related to
+            /// the plane of rotation the same way as in `Bivec3::from_normalized_axis`, so
+            /// `Rotor3::from_angle_plane(angle, Bivec3::from_normalized_axis(axis))` gives back this rotor.
+            ///
+            /// See `into_angle_plane` for the range of the angle.
+            #[inline]
+            pub fn into_axis_angle(self) -> ($vt, $t) {
+                let (angle, plane) = self.into_angle_plane();
+                ($vt::new(plane.yz, plane.xz, plane.xy), angle)
+            }
+
+            /// The `(roll, pitch, yaw)` angles of this rotor, which must be normalized. This is the inverse
+            /// of `Rotor3::from_euler_angles`.
+            ///
+            /// Roll and yaw are in the range `[-pi, pi]` and pitch is in the range `[-pi/2, pi/2]`. When
+            /// pitch is (close to) `+-pi/2`, roll and yaw rotate in the same plane, so all of the rotation
+            /// is attributed to yaw and roll is 0.
+            #[inline]
+            pub fn into_euler_angles(self) -> ($t, $t, $t) {
+                let m = self.into_matrix();
+                let sin_pitch = -m.cols[2].y;
+                if sin_pitch.abs() < 0.9999 {
+                    let roll = m.cols[0].y.atan2(m.cols[1].y);
+                    let pitch = sin_pitch.asin();
+                    let yaw = -m.cols[2].x.atan2(m.cols[2].z);
+                    (roll, pitch, yaw)
+                } else {
+                    let pitch = ($pi / 2.0).copysign(sin_pitch);
+                    let yaw = -(-m.cols[0].z).atan2(m.cols[0].x);
+                    (0.0, pitch, yaw)
+                }
+            }
+        })+
+    };
+}
+
+impl_into_angles!(
+    Rotor3, Rotor2 => (Bivec3, Bivec2, Vec3, f32, std::f32::consts::PI),
+    DRotor3, DRotor2 => (DBivec3, DBivec2, DVec3, f64, std::f64::consts::PI)
+);
+
+macro_rules! impl_wide_into_angles {
+    ($($rt:ident, $r2t:ident => ($bt:ident, $b2t:ident, $vt:ident, $t:ident)),+) => {
+        $(impl $r2t {
+            /// The angle of rotation of this rotor, which must be normalized. This is the inverse of
+            /// `from_angle`, and the result is in the range `[-2pi, 2pi]`.
+            #[inline]
+            pub fn into_angle(self) -> $t {
+                self.bv.xy.atan2(self.s) * $t::from(-2.0)
+            }
+
+            /// The bivector describing the plane, orientation and angle of rotation of this rotor, which
+            /// must be normalized. This is the inverse of `from_angle_plane`.
+            #[inline]
+            pub fn into_angle_plane(self) -> $b2t {
+                $b2t::new(self.into_angle())
+            }
+        }
+
+        impl $rt {
+            /// The angle and plane of rotation of this rotor, which must be normalized. This is the inverse
+            /// of `from_angle_plane`.
+            ///
+            /// The angle is in the range `[0, 2pi]`. The plane is normalized, and is arbitrarily chosen as the
+            /// xy plane in lanes where the angle is 0 or 2pi.
+            #[inline]
+            pub fn into_angle_plane(self) -> ($t, $bt) {
+                let sin = self.bv.mag();
+                let angle = sin.atan2(self.s) * $t::from(2.0);
+                let has_plane = sin.cmp_gt($t::from(1e-6));
+                let plane = self.bv * ($t::from(-1.0) / has_plane.blend(sin, $t::from(1.0)));
+                let plane = $bt::merge(has_plane, plane, $bt::unit_xy());
+                (angle, plane)
+            }
+
+            /// The axis and angle of rotation of this rotor, which must be normalized. The axis is related to
+            /// the plane of rotation the same way as in `from_normalized_axis` on the bivector type.
+            ///
+            /// See `into_angle_plane` for the range of the angle.
+            #[inline]
+            pub fn into_axis_angle(self) -> ($vt, $t) {
+                let (angle, plane) = self.into_angle_plane();
+                ($vt::new(plane.yz, plane.xz, plane.xy), angle)
+            }
+
+            /// The `(roll, pitch, yaw)` angles of this rotor, which must be normalized. This is the inverse
+            /// of `from_euler_angles`.
+            ///
+            /// Roll and yaw are in the range `[-pi, pi]` and pitch is in the range `[-pi/2, pi/2]`. In lanes
+            /// where pitch is (close to) `+-pi/2`, all of the rotation is attributed to yaw and roll is 0.
+            #[inline]
+            pub fn into_euler_angles(self) -> ($t, $t, $t) {
+                let one = $t::from(1.0);
+                let m = self.into_matrix();
+                let sin_pitch = -m.cols[2].y;
+                let pitch = sin_pitch.max(-one).min(one).asin();
+                let locked = sin_pitch.abs().cmp_ge($t::from(0.9999));
+
+                let roll = m.cols[0].y.atan2(m.cols[1].y);
+                let yaw = -m.cols[2].x.atan2(m.cols[2].z);
+                let locked_yaw = -(-m.cols[0].z).atan2(m.cols[0].x);
+
+                (
+                    locked.blend($t::from(0.0), roll),
+                    pitch,
+                    locked.blend(locked_yaw, yaw),
+                )
+            }
+        })+
+    };
+}
+
+impl_wide_into_angles!(
+    WRotor3, WRotor2 => (WBivec3, WBivec2, Wec3, f32x4),
+    WRotor3x8, WRotor2x8 => (WBivec3x8, WBivec2x8, Wec3x8, f32x8),
+    DWRotor3, DWRotor2 => (DWBivec3, DWBivec2, DWec3, f64x4)
+);
+
+macro_rules! impl_from_rotation_matrix {
+    ($($rt:ident => ($mt:ident, $m4t:ident, $vt:ident, $bt:ident, $t:ident)),+) => {
+        $(impl $rt {
+            /// Construct a rotor from a rotation matrix. This is the inverse of `into_matrix`.
+            ///
+            /// The columns of `m` are normalized first and the resulting rotor is normalized, so small amounts of
+            /// scale or shear (e.g. from accumulated floating point error) are tolerated. The result always has a
+            /// non-negative scalar part.
+            #[inline]
+            pub fn from_rotation_matrix(m: $mt) -> Self {
+                let c0 = m.cols[0].normalized();
+                let c1 = m.cols[1].normalized();
+                let c2 = m.cols[2].normalized();
+                let (m00, m10, m20) = (c0.x, c0.y, c0.z);
+                let (m01, m11, m21) = (c1.x, c1.y, c1.z);
+                let (m02, m12, m22) = (c2.x, c2.y, c2.z);
+
+                // Compute the largest component first from the diagonal, and the others from
+                // the off-diagonal terms divided by it, so that we never divide by a small value.
+                let trace = m00 + m11 + m22;
+                let rotor = if trace > 0.0 {
+                    let s = (1.0 + trace).sqrt() * 0.5;
+                    let f = 0.25 / s;
+                    Self::new(s, $bt::new((m01 - m10) * f, (m02 - m20) * f, (m12 - m21) * f))
+                } else if m00 > m11 && m00 > m22 {
+                    let yz = (1.0 + m00 - m11 - m22).sqrt() * 0.5;
+                    let f = 0.25 / yz;
+                    Self::new((m12 - m21) * f, $bt::new((m02 + m20) * f, -(m01 + m10) * f, yz))
+                } else if m11 > m22 {
+                    let xz = (1.0 - m00 + m11 - m22).sqrt() * 0.5;
+                    let f = 0.25 / xz;
+                    Self::new((m02 - m20) * f, $bt::new(-(m12 + m21) * f, xz, -(m01 + m10) * f))
+                } else {
+                    let xy = (1.0 - m00 - m11 + m22).sqrt() * 0.5;
+                    let f = 0.25 / xy;
+                    Self::new((m01 - m10) * f, $bt::new(xy, -(m12 + m21) * f, (m02 + m20) * f))
+                };
+
+                let rotor = if rotor.s < 0.0 { rotor * -1.0 } else { rotor };
+                rotor.normalized()
+            }
+
+            /// Construct a rotor from the rotation part (upper-left 3x3) of a homogeneous transformation matrix.
+            ///
+            /// See `from_rotation_matrix`; note that the translation part of `m` is ignored.
+            #[inline]
+            pub fn from_rotation_matrix4(m: $m4t) -> Self {
+                Self::from_rotation_matrix($mt::new(
+                    $vt::from(m.cols[0]),
+                    $vt::from(m.cols[1]),
+                    $vt::from(m.cols[2]),
+                ))
+            }
+        })+
+    };
+}
+
+impl_from_rotation_matrix!(
+    Rotor3 => (Mat3, Mat4, Vec3, Bivec3, f32),
+    DRotor3 => (DMat3, DMat4, DVec3, DBivec3, f64)
+);
+
+macro_rules! impl_wide_from_rotation_matrix {
+    ($($rt:ident => ($mt:ident, $m4t:ident, $vt:ident, $bt:ident, $t:ident)),+) => {
+        $(impl $rt {
+            /// Construct a rotor from a rotation matrix. This is the inverse of `into_matrix`.
+            ///
+            /// The columns of `m` are normalized first and the resulting rotor is normalized, so small amounts of
+            /// scale or shear (e.g. from accumulated floating point error) are tolerated. The result always has a
+            /// non-negative scalar part.
+            #[inline]
+            pub fn from_rotation_matrix(m: $mt) -> Self {
+                let zero = $t::from(0.0);
+                let one = $t::from(1.0);
+                let half = $t::from(0.5);
+                let quarter = $t::from(0.25);
+
+                let c0 = m.cols[0].normalized();
+                let c1 = m.cols[1].normalized();
+                let c2 = m.cols[2].normalized();
+                let (m00, m10, m20) = (c0.x, c0.y, c0.z);
+                let (m01, m11, m21) = (c1.x, c1.y, c1.z);
+                let (m02, m12, m22) = (c2.x, c2.y, c2.z);
+
+                // Compute all four candidates (each one dividing by a different component) and pick the
+                // one that divides by the largest component in each lane.
+                let trace = m00 + m11 + m22;
+                let s = (one + trace).max(zero).sqrt() * half;
+                let f = quarter / s;
+                let from_s = Self::new(s, $bt::new((m01 - m10) * f, (m02 - m20) * f, (m12 - m21) * f));
+
+                let yz = (one + m00 - m11 - m22).max(zero).sqrt() * half;
+                let f = quarter / yz;
+                let from_yz = Self::new((m12 - m21) * f, $bt::new((m02 + m20) * f, -(m01 + m10) * f, yz));
+
+                let xz = (one - m00 + m11 - m22).max(zero).sqrt() * half;
+                let f = quarter / xz;
+                let from_xz = Self::new((m02 - m20) * f, $bt::new(-(m12 + m21) * f, xz, -(m01 + m10) * f));
+
+                let xy = (one - m00 - m11 + m22).max(zero).sqrt() * half;
+                let f = quarter / xy;
+                let from_xy = Self::new((m01 - m10) * f, $bt::new(xy, -(m12 + m21) * f, (m02 + m20) * f));
+
+                let use_yz = m00.cmp_gt(m11) & m00.cmp_gt(m22);
+                let use_xz = m11.cmp_gt(m22);
+                let rotor = <Self as Lanes>::merge(use_xz, from_xz, from_xy);
+                let rotor = <Self as Lanes>::merge(use_yz, from_yz, rotor);
+                let rotor = <Self as Lanes>::merge(trace.cmp_gt(zero), from_s, rotor);
+
+                let rotor = rotor * rotor.s.cmp_lt(zero).blend(-one, one);
+                rotor.normalized()
+            }
+
+            /// Construct a rotor from the rotation part (upper-left 3x3) of a homogeneous transformation matrix.
+            ///
+            /// See `from_rotation_matrix`; note that the translation part of `m` is ignored.
+            #[inline]
+            pub fn from_rotation_matrix4(m: $m4t) -> Self {
+                Self::from_rotation_matrix($mt::new(
+                    $vt::from(m.cols[0]),
+                    $vt::from(m.cols[1]),
+                    $vt::from(m.cols[2]),
+                ))
+            }
+        })+
+    };
+}
+
+impl_wide_from_rotation_matrix!(
+    WRotor3 => (Wat3, Wat4, Wec3, WBivec3, f32x4),
+    WRotor3x8 => (Wat3x8, Wat4x8, Wec3x8, WBivec3x8, f32x8),
+    DWRotor3 => (DWat3, DWat4, DWec3, DWBivec3, f64x4)
+);
 
 impl PartialEq for Rotor2 {
     fn eq(&self, other: &Self) -> bool {
@@ -697,6 +1516,36 @@ impl PartialEq for Rotor3 {
     }
 }
 
+impl PartialEq for DRotor2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.s == other.s && self.bv == other.bv
+    }
+}
+
+impl PartialEq for DRotor3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.s == other.s && self.bv == other.bv
+    }
+}
+
+impl PartialEq for Rotor4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.s == other.s && self.bv == other.bv && self.xyzw == other.xyzw
+    }
+}
+
+impl PartialEq for DRotor4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.s == other.s && self.bv == other.bv && self.xyzw == other.xyzw
+    }
+}
+
+impl_f64_conversions!(
+    Rotor2 => DRotor2 { s, bv },
+    Rotor3 => DRotor3 { s, bv },
+    Rotor4 => DRotor4 { s, bv, xyzw }
+);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -743,4 +1592,274 @@ mod test {
         println!("{:#?} {:#?}", rotor_abbc, res);
         assert!(c.eq_eps(res));
     }
+
+    #[test]
+    pub fn compose_rotor_roundtrip_f64() {
+        let a = DVec3::new(0.25, -5.0, 1.0).normalized();
+        let b = DVec3::new(-5.0, 2.0, 4.0).normalized();
+        let c = DVec3::new(-3.0, 0.0, -1.0).normalized();
+        let rotor_ab = DRotor3::from_rotation_between(a, b);
+        let rotor_bc = DRotor3::from_rotation_between(b, c);
+        let res = (rotor_bc * rotor_ab) * a;
+        assert!(c.eq_eps(res));
+        assert!((res - c).mag() < 1e-12);
+
+        let narrowed = (rotor_bc * rotor_ab).into_f32() * a.into_f32();
+        assert!(DVec3::from(narrowed).eq_eps(res));
+    }
+
+    #[test]
+    pub fn wide_rotate_vector_f64() {
+        let vecs = [
+            DVec3::new(1.0, 2.0, -5.0),
+            DVec3::new(1.0, 1.0, 1.0),
+            DVec3::new(2.0, 3.0, -3.0),
+            DVec3::new(-4.0, 0.5, 0.0),
+        ];
+        let rotor = DRotor3::from_euler_angles(0.3, -1.2, 2.5);
+        let wide_rotor = DWRotor3::new(
+            f64x4::from(rotor.s),
+            DWBivec3::new(f64x4::from(rotor.bv.xy), f64x4::from(rotor.bv.xz), f64x4::from(rotor.bv.yz)),
+        );
+        let rotated: [DVec3; 4] = (wide_rotor * DWec3::from(vecs)).into();
+        for (v, r) in vecs.iter().zip(rotated.iter()) {
+            assert!((rotor * *v - *r).mag() < 1e-12);
+        }
+    }
+
+    #[test]
+    pub fn exp_log_roundtrip() {
+        let rotor = Rotor3::from_euler_angles(0.3, -1.2, 2.5);
+        assert!(rotor.log().exp().eq_eps(rotor));
+
+        let plane = Bivec3::new(1.0, -2.0, 0.5).normalized();
+        let from_exp = (plane * (-1.3 / 2.0)).exp();
+        assert!(from_exp.eq_eps(Rotor3::from_angle_plane(1.3, plane)));
+        assert!(from_exp.log().eq_eps(plane * (-1.3 / 2.0)));
+
+        assert!(Bivec3::zero().exp().eq_eps(Rotor3::identity()));
+        assert!(Rotor3::identity().log().eq_eps(Bivec3::zero()));
+
+        let rotor = Rotor2::from_angle(-2.0);
+        assert!(rotor.log().eq_eps(Bivec2::new(1.0)));
+        assert!(rotor.log().exp().eq_eps(rotor));
+    }
+
+    #[test]
+    pub fn wide_exp_log() {
+        let rotors = [
+            Rotor3::identity(),
+            Rotor3::from_euler_angles(0.3, -1.2, 2.5),
+            Rotor3::from_rotation_xz(3.0),
+            Rotor3::from_rotation_yz(-0.1),
+        ];
+        let wide = WRotor3::from_lanes(&rotors);
+        let logs = wide.log();
+        let roundtrip = logs.exp();
+        for (i, rotor) in rotors.iter().enumerate() {
+            assert!(logs.extract(i).eq_eps(rotor.log()));
+            assert!(roundtrip.extract(i).eq_eps(*rotor));
+        }
+
+        let rotors = [
+            Rotor2::from_angle(0.0),
+            Rotor2::from_angle(1.0),
+            Rotor2::from_angle(-2.5),
+            Rotor2::from_angle(3.9),
+        ];
+        let logs = WRotor2::from_lanes(&rotors).log();
+        for (i, rotor) in rotors.iter().enumerate() {
+            assert!(logs.extract(i).eq_eps(rotor.log()));
+        }
+    }
+
+    #[test]
+    pub fn into_angles_roundtrip() {
+        let (roll, pitch, yaw) = Rotor3::from_euler_angles(0.3, -1.2, 2.5).into_euler_angles();
+        assert!(roll.eq_eps(0.3) && pitch.eq_eps(-1.2) && yaw.eq_eps(2.5));
+
+        let rotor = Rotor3::from_euler_angles(0.7, std::f32::consts::FRAC_PI_2, -0.4);
+        let (roll, pitch, yaw) = rotor.into_euler_angles();
+        assert!(roll.eq_eps(0.0) && pitch.eq_eps(std::f32::consts::FRAC_PI_2));
+        let v = Vec3::new(1.0, 2.0, -5.0);
+        assert!((Rotor3::from_euler_angles(roll, pitch, yaw) * v).eq_eps(rotor * v));
+
+        let plane = Bivec3::new(1.0, -2.0, 0.5).normalized();
+        let (angle, extracted) = Rotor3::from_angle_plane(4.0, plane).into_angle_plane();
+        assert!(angle.eq_eps(4.0) && extracted.eq_eps(plane));
+
+        let axis = Vec3::new(0.2, -1.0, 3.0).normalized();
+        let rotor = Rotor3::from_angle_plane(0.8, Bivec3::from_normalized_axis(axis));
+        let (extracted, angle) = rotor.into_axis_angle();
+        assert!(angle.eq_eps(0.8) && extracted.eq_eps(axis));
+
+        assert!(Rotor2::from_angle(-2.2).into_angle().eq_eps(-2.2));
+    }
+
+    #[test]
+    pub fn wide_into_angles() {
+        let rotors = [
+            Rotor3::identity(),
+            Rotor3::from_euler_angles(0.3, -1.2, 2.5),
+            Rotor3::from_euler_angles(0.7, -std::f32::consts::FRAC_PI_2, -0.4),
+            Rotor3::from_angle_plane(5.0, Bivec3::new(0.0, 0.6, 0.8)),
+        ];
+        let wide = WRotor3::from_lanes(&rotors);
+        let (roll, pitch, yaw) = wide.into_euler_angles();
+        let (angle, plane) = wide.into_angle_plane();
+        for (i, rotor) in rotors.iter().enumerate() {
+            let (r, p, y) = rotor.into_euler_angles();
+            assert!(roll.extract(i).eq_eps(r) && pitch.extract(i).eq_eps(p) && yaw.extract(i).eq_eps(y));
+            let (a, pl) = rotor.into_angle_plane();
+            assert!(angle.extract(i).eq_eps(a) && plane.extract(i).eq_eps(pl));
+        }
+    }
+
+    #[test]
+    pub fn from_rotation_matrix_roundtrip() {
+        let rotors = [
+            Rotor3::identity(),
+            Rotor3::from_euler_angles(0.3, -1.2, 2.5),
+            Rotor3::from_rotation_xy(3.1),
+            Rotor3::from_rotation_xz(-3.1),
+            Rotor3::from_rotation_yz(3.0),
+        ];
+        for rotor in rotors.iter() {
+            let rotor = if rotor.s < 0.0 { *rotor * -1.0 } else { *rotor };
+            assert!(Rotor3::from_rotation_matrix(rotor.into_matrix()).eq_eps(rotor));
+            let homogeneous = rotor.into_matrix().into_homogeneous();
+            assert!(Rotor3::from_rotation_matrix4(homogeneous).eq_eps(rotor));
+
+            // tolerate scale drift
+            let mut drifted = rotor.into_matrix();
+            drifted.cols[0] *= 1.02;
+            drifted.cols[2] *= 0.97;
+            assert!(Rotor3::from_rotation_matrix(drifted).eq_eps(rotor));
+        }
+
+        let rotor = Rotor2::from_angle(2.9);
+        let mut m = rotor.into_matrix();
+        m.cols[1] *= 1.1;
+        assert!(Rotor2::from_rotation_matrix(m).eq_eps(rotor));
+    }
+
+    #[test]
+    pub fn wide_from_rotation_matrix() {
+        let rotors = [
+            Rotor3::from_euler_angles(0.3, -1.2, 2.5),
+            Rotor3::from_rotation_xy(3.1),
+            Rotor3::from_rotation_xz(-3.1),
+            Rotor3::from_rotation_yz(3.0),
+        ];
+        let wide = WRotor3::from_rotation_matrix(WRotor3::from_lanes(&rotors).into_matrix());
+        for (i, rotor) in rotors.iter().enumerate() {
+            assert!(wide.extract(i).eq_eps(Rotor3::from_rotation_matrix(rotor.into_matrix())));
+        }
+    }
+
+    #[test]
+    pub fn quaternion_roundtrip() {
+        // a rotation of 90 degrees around +z maps +x to +y
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        let rotor = Rotor3::from_quaternion_xyzw([0.0, 0.0, half, half]);
+        assert!((rotor * Vec3::unit_x()).eq_eps(Vec3::unit_y()));
+
+        let rotor = Rotor3::from_euler_angles(0.3, -1.2, 2.5);
+        let [x, y, z, w] = rotor.into_quaternion_xyzw();
+        assert!(Rotor3::from_quaternion_xyzw([x, y, z, w]).eq_eps(rotor));
+
+        // v' = v + 2w(q x v) + 2q x (q x v)
+        let q = Vec3::new(x, y, z);
+        let v = Vec3::new(1.0, 2.0, -5.0);
+        let t = q.cross(v) * 2.0;
+        assert!((rotor * v).eq_eps(v + t * w + q.cross(t)));
+
+        let a = Rotor3::from_rotation_xz(0.7);
+        let b = Rotor3::from_rotation_yz(-1.1);
+        let [ax, ay, az, aw] = a.into_quaternion_xyzw();
+        let [bx, by, bz, bw] = b.into_quaternion_xyzw();
+        let product = [
+            aw * bx + ax * bw + ay * bz - az * by,
+            aw * by - ax * bz + ay * bw + az * bx,
+            aw * bz + ax * by - ay * bx + az * bw,
+            aw * bw - ax * bx - ay * by - az * bz,
+        ];
+        assert!(Rotor3::from_quaternion_xyzw(product).eq_eps(a * b));
+
+        let wide = WRotor3::from_quaternion_xyzw([
+            f32x4::from(x),
+            f32x4::from(y),
+            f32x4::from(z),
+            f32x4::from(w),
+        ]);
+        assert!(wide.extract(2).eq_eps(rotor));
+        assert!(wide.into_quaternion_xyzw()[1].eq_eps(f32x4::from(y)));
+    }
+
+    #[test]
+    pub fn rotor4_simple_rotations() {
+        let quarter = std::f32::consts::FRAC_PI_2;
+        assert!((Rotor4::from_rotation_xy(quarter) * Vec4::unit_x()).eq_eps(Vec4::unit_y()));
+        assert!((Rotor4::from_rotation_zw(quarter) * Vec4::unit_z()).eq_eps(Vec4::unit_w()));
+        assert!((Rotor4::from_rotation_yw(quarter) * Vec4::unit_x()).eq_eps(Vec4::unit_x()));
+
+        // rotations which don't involve w behave like their 3d counterparts
+        let v = Vec3::new(1.0, 2.0, -5.0);
+        let r3 = Rotor3::from_rotation_xz(0.4) * Rotor3::from_rotation_yz(-1.3);
+        let r4 = Rotor4::from_rotation_xz(0.4) * Rotor4::from_rotation_yz(-1.3);
+        let rotated = r4 * Vec4::new(v.x, v.y, v.z, 3.0);
+        assert!(Vec3::from(rotated).eq_eps(r3 * v));
+        assert!(rotated.w.eq_eps(3.0));
+
+        let from = Vec4::new(1.0, 2.0, 0.5, -1.0).normalized();
+        let to = Vec4::new(-0.3, 0.0, 2.0, 1.0).normalized();
+        assert!((Rotor4::from_rotation_between(from, to) * from).eq_eps(to));
+        assert!(from.wedge(to).eq_eps(-to.wedge(from)));
+    }
+
+    #[test]
+    pub fn rotor4_double_rotation() {
+        let a = Rotor4::from_rotation_xy(0.7) * Rotor4::from_rotation_zw(-1.9);
+        let b = Rotor4::from_rotation_xw(2.2) * Rotor4::from_rotation_yz(0.3);
+        assert!(a.xyzw.abs() > 0.1);
+        assert!(a.mag().eq_eps(1.0));
+
+        let v = Vec4::new(1.0, -2.0, 0.5, 3.0);
+        assert!((a * v).eq_eps(a.into_matrix() * v));
+        assert!(((a * b) * v).eq_eps(a * (b * v)));
+        assert!((a.reversed() * (a * v)).eq_eps(v));
+        assert!((a * b).mag().eq_eps(1.0));
+        assert!(v.rotated_by(b).mag().eq_eps(v.mag()));
+
+        let wide = WRotor4::from_lanes(&[a, b, a * b, Rotor4::identity()]);
+        let rotated = wide * Wec4::splat(v);
+        for (i, rotor) in [a, b, a * b, Rotor4::identity()].iter().enumerate() {
+            assert!(rotated.extract(i).eq_eps(*rotor * v));
+        }
+    }
+
+    #[test]
+    pub fn wide_rotate_vector_x8() {
+        let vecs = [
+            Vec3::new(1.0, 2.0, -5.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(2.0, 3.0, -3.0),
+            Vec3::new(-4.0, 0.5, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(3.0, -2.0, 0.5),
+            Vec3::new(-1.0, -1.0, -1.0),
+            Vec3::new(0.25, 7.0, 2.0),
+        ];
+        let rotor = Rotor3::from_euler_angles(0.3, -1.2, 2.5);
+        let wide_rotor = WRotor3x8::new(
+            f32x8::from(rotor.s),
+            WBivec3x8::new(f32x8::from(rotor.bv.xy), f32x8::from(rotor.bv.xz), f32x8::from(rotor.bv.yz)),
+        );
+        let rotated: [Vec3; 8] = (wide_rotor * Wec3x8::from(vecs)).into();
+        for (v, r) in vecs.iter().zip(rotated.iter()) {
+            assert!((rotor * *v).eq_eps(*r));
+        }
+        let round_trip: [Vec3; 8] = Wec3x8::from(vecs).into();
+        assert_eq!(round_trip, vecs);
+    }
 }
@@ -2,11 +2,11 @@ use crate::*;
 use approx::{AbsDiffEq, UlpsEq};
 
 macro_rules! impl_approx {
-    ($($n:ty: $($member:ident),+;)*) => {$(
+    ($($t:ident => { $($n:ty: $($member:ident),+;)* })*) => {$($(
         impl AbsDiffEq for $n {
-            type Epsilon = f32;
+            type Epsilon = $t;
             fn default_epsilon() -> Self::Epsilon {
-                f32::default_epsilon()
+                $t::default_epsilon()
             }
             fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
                 true $(&& self.$member.abs_diff_eq(&other.$member, epsilon))*
@@ -18,7 +18,7 @@ macro_rules! impl_approx {
 
         impl UlpsEq<$n> for $n {
             fn default_max_ulps() -> u32 {
-                f32::default_max_ulps()
+                $t::default_max_ulps()
             }
 
             fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
@@ -29,17 +29,36 @@ macro_rules! impl_approx {
                 false $(|| self.$member.ulps_ne(&other.$member, epsilon, max_ulps))*
             }
         }
-    )*};
+    )*)*};
 }
 
 impl_approx! {
-    Vec2: x, y;
-    Vec3: x, y, z;
-    Vec4: x, y, z, w;
-    Bivec2: xy;
-    Bivec3: xy, xz, yz;
-    Rotor2: s, bv;
-    Rotor3: s, bv;
+    f32 => {
+        Vec2: x, y;
+        Vec3: x, y, z;
+        Vec4: x, y, z, w;
+        Bivec2: xy;
+        Bivec3: xy, xz, yz;
+        Rotor2: s, bv;
+        Rotor3: s, bv;
+        Bivec4: xy, xz, xw, yz, yw, zw;
+        Rotor4: s, bv, xyzw;
+        Trivec3: xyz;
+        Multivec3: s, v, bv, tv;
+    }
+    f64 => {
+        DVec2: x, y;
+        DVec3: x, y, z;
+        DVec4: x, y, z, w;
+        DBivec2: xy;
+        DBivec3: xy, xz, yz;
+        DRotor2: s, bv;
+        DRotor3: s, bv;
+        DBivec4: xy, xz, xw, yz, yw, zw;
+        DRotor4: s, bv, xyzw;
+        DTrivec3: xyz;
+        DMultivec3: s, v, bv, tv;
+    }
 }
 
 #[cfg(test)]
@@ -52,6 +71,7 @@ mod approx_tests {
         assert_abs_diff_eq!(Vec2::unit_x(), Vec2::unit_x());
         assert_abs_diff_eq!(Vec3::unit_x(), Vec3::unit_x());
         assert_abs_diff_eq!(Vec4::unit_x(), Vec4::unit_x());
+        assert_abs_diff_eq!(DVec3::unit_x(), DVec3::unit_x());
     }
 
     #[test]
@@ -59,6 +79,7 @@ mod approx_tests {
         assert_abs_diff_ne!(Vec2::unit_x(), Vec2::unit_y());
         assert_abs_diff_ne!(Vec3::unit_x(), Vec3::unit_y());
         assert_abs_diff_ne!(Vec4::unit_x(), Vec4::unit_y());
+        assert_abs_diff_ne!(DVec3::unit_x(), DVec3::unit_y());
     }
 
     #[test]
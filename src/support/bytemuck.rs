@@ -9,13 +9,24 @@ macro_rules! impl_pod {
 }
 
 impl_pod! {
-    Vec2, Wec2, Vec2i, Vec2u,
-    Vec3, Wec3, Vec3i, Vec3u,
-    Vec4, Wec4, Vec4i, Vec4u,
-    Bivec2, Bivec3,
-    Mat2, Wat2,
-    Mat3, Wat3,
-    Mat4, Wat4,
-    Rotor2, WRotor2,
-    Rotor3, WRotor3,
+    Vec2, Wec2, Wec2x8, DVec2, DWec2, Vec2i, Vec2u,
+    Vec3, Wec3, Wec3x8, DVec3, DWec3, Vec3i, Vec3u,
+    Vec4, Wec4, Wec4x8, DVec4, DWec4, Vec4i, Vec4u,
+    Bivec2, DBivec2, Bivec3, DBivec3, Bivec4, DBivec4,
+    Trivec3, WTrivec3, WTrivec3x8, DTrivec3, DWTrivec3,
+    Multivec3, WMultivec3, WMultivec3x8, DMultivec3, DWMultivec3,
+    Point3, WPoint3, WPoint3x8, DPoint3, DWPoint3,
+    Line3, WLine3, WLine3x8, DLine3, DWLine3,
+    Plane3, WPlane3, WPlane3x8, DPlane3, DWPlane3,
+    Motor3, WMotor3, WMotor3x8, DMotor3, DWMotor3,
+    Mat2, Wat2, Wat2x8, DMat2, DWat2,
+    Mat3, Wat3, Wat3x8, DMat3, DWat3,
+    Mat4, Wat4, Wat4x8, DMat4, DWat4,
+    Mat3x2, Wat3x2, Wat3x2x8, DMat3x2, DWat3x2,
+    Mat2x3, Wat2x3, Wat2x3x8, DMat2x3, DWat2x3,
+    Mat4x3, Wat4x3, Wat4x3x8, DMat4x3, DWat4x3,
+    Mat3x4, Wat3x4, Wat3x4x8, DMat3x4, DWat3x4,
+    Rotor2, WRotor2, WRotor2x8, DRotor2, DWRotor2,
+    Rotor3, WRotor3, WRotor3x8, DRotor3, DWRotor3,
+    Rotor4, WRotor4, WRotor4x8, DRotor4, DWRotor4,
 }
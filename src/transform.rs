@@ -115,8 +115,8 @@ macro_rules! isometries {
 
             #[inline]
             pub fn into_homogeneous_matrix(self) -> $mt {
-                self.rotation.into_matrix().into_homogeneous()
-                    * $mt::from_translation(self.translation)
+                $mt::from_translation(self.translation)
+                    * self.rotation.into_matrix().into_homogeneous()
             }
         }
 
@@ -161,7 +161,10 @@ macro_rules! isometries {
 
 isometries!(
     Isometry2 => (Mat3, Rotor2, Vec2, f32), WIsometry2 => (Wat3, WRotor2, Wec2, f32x4),
-    Isometry3 => (Mat4, Rotor3, Vec3, f32), WIsometry3 => (Wat4, WRotor3, Wec3, f32x4)
+    Isometry3 => (Mat4, Rotor3, Vec3, f32), WIsometry3 => (Wat4, WRotor3, Wec3, f32x4),
+    WIsometry2x8 => (Wat3x8, WRotor2x8, Wec2x8, f32x8), WIsometry3x8 => (Wat4x8, WRotor3x8, Wec3x8, f32x8),
+    DIsometry2 => (DMat3, DRotor2, DVec2, f64), DWIsometry2 => (DWat3, DWRotor2, DWec2, f64x4),
+    DIsometry3 => (DMat4, DRotor3, DVec3, f64), DWIsometry3 => (DWat4, DWRotor3, DWec3, f64x4)
 );
 
 macro_rules! similarities {
@@ -298,8 +301,8 @@ macro_rules! similarities {
             #[inline]
             pub fn into_homogeneous_matrix(self) -> $mth {
                 let scale_rot = $mt::from_scale(self.scale) * self.rotation.into_matrix();
-                scale_rot.into_homogeneous()
-                    * $mth::from_translation(self.translation)
+                $mth::from_translation(self.translation)
+                    * scale_rot.into_homogeneous()
             }
         }
 
@@ -345,7 +348,266 @@ macro_rules! similarities {
 
 similarities!(
     Similarity2 => (Mat2, Mat3, Rotor2, Vec2, f32), WSimilarity2 => (Wat2, Wat3, WRotor2, Wec2, f32x4),
-    Similarity3 => (Mat3, Mat4, Rotor3, Vec3, f32), WSimilarity3 => (Wat3, Wat4, WRotor3, Wec3, f32x4)
+    Similarity3 => (Mat3, Mat4, Rotor3, Vec3, f32), WSimilarity3 => (Wat3, Wat4, WRotor3, Wec3, f32x4),
+    WSimilarity2x8 => (Wat2x8, Wat3x8, WRotor2x8, Wec2x8, f32x8), WSimilarity3x8 => (Wat3x8, Wat4x8, WRotor3x8, Wec3x8, f32x8),
+    DSimilarity2 => (DMat2, DMat3, DRotor2, DVec2, f64), DWSimilarity2 => (DWat2, DWat3, DWRotor2, DWec2, f64x4),
+    DSimilarity3 => (DMat3, DMat4, DRotor3, DVec3, f64), DWSimilarity3 => (DWat3, DWat4, DWRotor3, DWec3, f64x4)
+);
+
+macro_rules! impl_try_from_mat4 {
+    ($($sn:ident => ($mt:ident, $mth:ident, $rt:ident, $t:ident)),+) => {
+        $(impl $sn {
+            /// Try to convert a homogeneous transformation matrix into a similarity. This is the inverse
+            /// of `into_homogeneous_matrix`.
+            ///
+            /// A matrix containing a reflection is converted into a similarity with a negative scale.
+            /// Returns `None` if the matrix is singular, or if it contains a non-uniform scale, shear or
+            /// projection larger than `epsilon` (measured after dividing out the scale). See
+            /// `Mat4::decompose_trs` for matrices with non-uniform scale.
+            pub fn try_from_mat4(m: $mth, epsilon: $t) -> Option<Self> {
+                let w = m.cols[3].w - 1.0;
+                if m.cols[0].w.abs() > epsilon
+                    || m.cols[1].w.abs() > epsilon
+                    || m.cols[2].w.abs() > epsilon
+                    || w.abs() > epsilon
+                {
+                    return None;
+                }
+
+                let linear = $mt::new(m.cols[0].xyz(), m.cols[1].xyz(), m.cols[2].xyz());
+                let scale = linear.determinant().cbrt();
+                if scale.abs() <= epsilon {
+                    return None;
+                }
+
+                let inv_scale = 1.0 / scale;
+                let rotation = $mt::new(
+                    linear.cols[0] * inv_scale,
+                    linear.cols[1] * inv_scale,
+                    linear.cols[2] * inv_scale,
+                );
+                let orthonormal = rotation.transposed() * rotation;
+                let identity = $mt::identity();
+                for (c, i) in orthonormal.cols.iter().zip(identity.cols.iter()) {
+                    if (*c - *i).abs().component_max() > epsilon {
+                        return None;
+                    }
+                }
+
+                Some(Self::new(m.cols[3].xyz(), $rt::from_rotation_matrix(rotation), scale))
+            }
+        })+
+    };
+}
+
+impl_try_from_mat4!(
+    Similarity3 => (Mat3, Mat4, Rotor3, f32),
+    DSimilarity3 => (DMat3, DMat4, DRotor3, f64)
+);
+
+macro_rules! affines {
+    ($($an:ident => ($mt:ident, $mth:ident, $ison:ident, $sn:ident, $rt:ident, $vt:ident, $t:ident)),+) => {
+        $(
+        /// An affine transformation, i.e. an arbitrary linear transformation *and then* a translation.
+        ///
+        /// Unlike a Similarity, this can represent non-uniform scaling (and shear), such as
+        /// the transforms of scene graph nodes. Note that the composition of non-uniform scales and
+        /// rotations is in general not a scale and a rotation anymore, which is why the linear part
+        /// is stored as a full matrix.
+        ///
+        /// You may want to us this type over the corresponding type of
+        /// homogeneous transformation matrix because it will be faster in most operations,
+        /// especially composition and inverse.
+        #[derive(Clone, Copy, Debug)]
+        #[repr(C)]
+        pub struct $an {
+            pub translation: $vt,
+            pub linear: $mt,
+        }
+
+        derive_default_identity!($an);
+
+        impl $an {
+            #[inline]
+            pub fn new(translation: $vt, linear: $mt) -> Self {
+                Self { translation, linear }
+            }
+
+            #[inline]
+            pub fn identity() -> Self {
+                Self { translation: $vt::zero(), linear: $mt::identity() }
+            }
+
+            /// Create an affine transformation which applies a non-uniform scale,
+            /// then a rotation, then a translation.
+            #[inline]
+            pub fn from_trs(translation: $vt, rotation: $rt, scale: $vt) -> Self {
+                Self::new(translation, rotation.into_matrix() * $mt::from_nonuniform_scale(scale))
+            }
+
+            /// Add a (non-uniform) scaling *before* this transformation.
+            ///
+            /// This means the scaling will only affect the linear
+            /// part of this transformation, not the translational part.
+            #[inline]
+            pub fn prepend_scaling(&mut self, scaling: $vt) {
+                self.linear = self.linear * $mt::from_nonuniform_scale(scaling);
+            }
+
+            /// Add a (non-uniform) scaling *after* this transformation.
+            ///
+            /// This means the scaling will affect both the linear
+            /// and translational parts of this transformation.
+            #[inline]
+            pub fn append_scaling(&mut self, scaling: $vt) {
+                self.linear = $mt::from_nonuniform_scale(scaling) * self.linear;
+                self.translation *= scaling;
+            }
+
+            /// Add a rotation *before* this transformation.
+            ///
+            /// This means the rotation will only affect the linear
+            /// part of this transformation, not the translational part.
+            #[inline]
+            pub fn prepend_rotation(&mut self, rotor: $rt) {
+                self.linear = self.linear * rotor.into_matrix();
+            }
+
+            /// Add a rotation *after* this transformation.
+            ///
+            /// This means the rotation will affect both the linear and
+            /// translational parts of this transformation.
+            #[inline]
+            pub fn append_rotation(&mut self, rotor: $rt) {
+                self.linear = rotor.into_matrix() * self.linear;
+                self.translation = rotor * self.translation;
+            }
+
+            /// Add a translation *before* this transformation.
+            ///
+            /// Doing so will mean that the translation being added will get
+            /// transformed by this transformation's linear part.
+            #[inline]
+            pub fn prepend_translation(&mut self, translation: $vt) {
+                self.translation += self.linear * translation;
+            }
+
+            /// Add a translation *after* this transformation.
+            ///
+            /// Doing so will mean that the translation being added will *not*
+            /// transformed by this transformation's linear part.
+            #[inline]
+            pub fn append_translation(&mut self, translation: $vt) {
+                self.translation += translation;
+            }
+
+            /// Prepend transformation by another affine transformation.
+            ///
+            /// This means that the transformation being applied will take place
+            /// *before* this transformation.
+            #[inline]
+            pub fn prepend_affine(&mut self, other: Self) {
+                *self = *self * other;
+            }
+
+            /// Append transformation by another affine transformation.
+            ///
+            /// This means that the transformation being applied will take place
+            /// *after* this transformation.
+            #[inline]
+            pub fn append_affine(&mut self, other: Self) {
+                *self = other * *self;
+            }
+
+            /// If the linear part of this transformation is not currently invertable, this function will
+            /// produce an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inverse(&mut self) {
+                self.linear.inverse();
+                self.translation = self.linear * (-self.translation);
+            }
+
+            /// If the linear part of this transformation is not currently invertable, this function will
+            /// return an invalid inverse. This status is not checked by the library.
+            #[inline]
+            pub fn inversed(mut self) -> Self {
+                self.inverse();
+                self
+            }
+
+            /// Transform a point, i.e. apply both the linear part and the translation.
+            #[inline]
+            pub fn transform_point(&self, point: $vt) -> $vt {
+                self.linear * point + self.translation
+            }
+
+            /// Transform a vector (a direction or an offset), i.e. apply only the linear part.
+            ///
+            /// Note that normals should be transformed by the inverse transpose of the linear part instead.
+            #[inline]
+            pub fn transform_vector(&self, vec: $vt) -> $vt {
+                self.linear * vec
+            }
+
+            #[inline]
+            pub fn into_homogeneous_matrix(self) -> $mth {
+                $mth::from_translation(self.translation) * self.linear.into_homogeneous()
+            }
+        }
+
+        impl Mul<$vt> for $an {
+            type Output = $vt;
+            #[inline]
+            fn mul(self, point: $vt) -> $vt {
+                self.transform_point(point)
+            }
+        }
+
+        impl Mul<$an> for $an {
+            type Output = Self;
+            #[inline]
+            fn mul(self, base: $an) -> $an {
+                $an::new(self.transform_point(base.translation), self.linear * base.linear)
+            }
+        }
+
+        impl From<$ison> for $an {
+            #[inline]
+            fn from(iso: $ison) -> Self {
+                Self::new(iso.translation, iso.rotation.into_matrix())
+            }
+        }
+
+        impl From<$sn> for $an {
+            #[inline]
+            fn from(sim: $sn) -> Self {
+                Self::new(sim.translation, $mt::from_scale(sim.scale) * sim.rotation.into_matrix())
+            }
+        }
+        )+
+    }
+}
+
+affines!(
+    Affine2 => (Mat2, Mat3, Isometry2, Similarity2, Rotor2, Vec2, f32),
+    WAffine2 => (Wat2, Wat3, WIsometry2, WSimilarity2, WRotor2, Wec2, f32x4),
+    Affine3 => (Mat3, Mat4, Isometry3, Similarity3, Rotor3, Vec3, f32),
+    WAffine3 => (Wat3, Wat4, WIsometry3, WSimilarity3, WRotor3, Wec3, f32x4),
+    WAffine2x8 => (Wat2x8, Wat3x8, WIsometry2x8, WSimilarity2x8, WRotor2x8, Wec2x8, f32x8),
+    WAffine3x8 => (Wat3x8, Wat4x8, WIsometry3x8, WSimilarity3x8, WRotor3x8, Wec3x8, f32x8),
+    DAffine2 => (DMat2, DMat3, DIsometry2, DSimilarity2, DRotor2, DVec2, f64),
+    DWAffine2 => (DWat2, DWat3, DWIsometry2, DWSimilarity2, DWRotor2, DWec2, f64x4),
+    DAffine3 => (DMat3, DMat4, DIsometry3, DSimilarity3, DRotor3, DVec3, f64),
+    DWAffine3 => (DWat3, DWat4, DWIsometry3, DWSimilarity3, DWRotor3, DWec3, f64x4)
+);
+
+impl_f64_conversions!(
+    Isometry2 => DIsometry2 { translation, rotation },
+    Isometry3 => DIsometry3 { translation, rotation },
+    Similarity2 => DSimilarity2 { translation, rotation, scale },
+    Similarity3 => DSimilarity3 { translation, rotation, scale },
+    Affine2 => DAffine2 { translation, linear },
+    Affine3 => DAffine3 { translation, linear }
 );
 
 #[cfg(all(test, feature = "approx"))]
@@ -365,6 +627,7 @@ mod test {
         assert_ulps_eq!(sim * vec, Vec3::new(10.0, 6.0, 1.0));
     }
 
+    #[test]
     fn isometry3_simple_transform_matrix() {
         let sim = Isometry3::new(
             Vec3::new(10.0, 5.0, 1.0),
@@ -386,6 +649,7 @@ mod test {
         assert_ulps_eq!(sim * vec, Vec3::new(10.0, 5.5, 1.0));
     }
 
+    #[test]
     fn similarity3_simple_transform_matrix() {
         let sim = Similarity3::new(
             Vec3::new(10.0, 5.0, 1.0),
@@ -396,4 +660,47 @@ mod test {
         let vec = Vec3::unit_x().into_homogeneous_point();
         assert_ulps_eq!(mat * vec, Vec4::new(10.0, 5.5, 1.0, 1.0));
     }
+
+    #[test]
+    fn similarity3_try_from_mat4() {
+        let sim = Similarity3::new(
+            Vec3::new(10.0, 5.0, 1.0),
+            Rotor3::from_euler_angles(0.3, -0.8, 1.2),
+            -0.5,
+        );
+        let back = Similarity3::try_from_mat4(sim.into_homogeneous_matrix(), 1e-4).unwrap();
+        let vec = Vec3::new(1.0, 2.0, 3.0);
+        assert_ulps_eq!(back * vec, sim * vec, max_ulps = 16);
+        assert_ulps_eq!(back.scale, -0.5);
+
+        let stretched = sim.into_homogeneous_matrix() * Mat4::from_nonuniform_scale(Vec4::new(1.0, 2.0, 1.0, 1.0));
+        assert!(Similarity3::try_from_mat4(stretched, 1e-4).is_none());
+        assert!(Similarity3::try_from_mat4(Mat4::from_scale_homogeneous(0.0), 1e-4).is_none());
+    }
+
+    #[test]
+    fn affine3_compose_inverse() {
+        let a = Affine3::from_trs(
+            Vec3::new(1.0, -2.0, 3.0),
+            Rotor3::from_euler_angles(0.3, -0.8, 1.2),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        let mut b = Affine3::from(Similarity3::new(Vec3::unit_y(), Rotor3::from_rotation_xy(ANGLE_90DEG), 2.0));
+        b.append_scaling(Vec3::new(1.0, 3.0, 1.0));
+        let vec = Vec3::new(0.5, 1.0, -2.0);
+
+        let mat = a.into_homogeneous_matrix() * b.into_homogeneous_matrix();
+        assert_ulps_eq!((a * b) * vec, (mat * vec.into_homogeneous_point()).xyz(), max_ulps = 16);
+        let trs = Mat4::from_trs(
+            Vec3::new(1.0, -2.0, 3.0),
+            Rotor3::from_euler_angles(0.3, -0.8, 1.2),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        assert_ulps_eq!(trs.transform_point3(vec), a * vec, max_ulps = 16);
+        assert_ulps_eq!(a.inversed() * (a * vec), vec, max_ulps = 16);
+        assert_ulps_eq!(a.transform_vector(vec), a.transform_point(vec) - a.translation);
+
+        let wide = WAffine3::from_lanes(&[a, b, a, b]).inversed();
+        assert_ulps_eq!(wide.extract(1) * (b * vec), vec, max_ulps = 16);
+    }
 }
@@ -0,0 +1,194 @@
+//! Trivectors, i.e. oriented volumes.
+//!
+//! A trivector is an *oriented volume*, and is equivalent to the result of the exterior (wedge) product
+//! of three vectors, i.e. `u ∧ v ∧ w`, or of a vector and a bivector. This means it is the *oriented volume*
+//! of the parallelepiped created by extending the three vectors.
+//!
+//! In 3d, there is only one basis volume, the xyz volume, which represents all of 3d space. As such, a 3d
+//! trivector has only *one* component, much like a 2d bivector. The unit trivector `xyz` is also known as the
+//! *pseudoscalar* of 3d space, and is commonly written as `I`. Its sign tells whether the three vectors it was
+//! created from form a right-handed (positive) or left-handed (negative) basis.
+use wide::{f32x4, f32x8, f64x4};
+
+use crate::util::*;
+use crate::*;
+
+use std::alloc::Layout;
+use std::ops::*;
+
+macro_rules! trivec3s {
+    ($($tn:ident => ($vt:ident, $bt:ident, $t:ident)),+) => {
+        $(
+        /// A trivector in 3d space, i.e. the 3d pseudoscalar.
+        ///
+        /// Please see the module level documentation for more information on trivectors!
+        #[derive(Clone, Copy, Debug, Default)]
+        #[repr(C)]
+        pub struct $tn {
+            pub xyz: $t,
+        }
+
+        impl $tn {
+            #[inline]
+            pub fn new(xyz: $t) -> Self {
+                Self {
+                    xyz
+                }
+            }
+
+            #[inline]
+            pub fn zero() -> Self {
+                Self::new($t::from(0.0))
+            }
+
+            #[inline]
+            pub fn unit_xyz() -> Self {
+                Self::new($t::from(1.0))
+            }
+
+            #[inline]
+            pub fn mag_sq(&self) -> $t {
+                self.xyz * self.xyz
+            }
+
+            #[inline]
+            pub fn mag(&self) -> $t {
+                self.xyz.abs()
+            }
+
+            /// The (Hodge) dual of this trivector, which is the scalar with the same magnitude.
+            #[inline]
+            pub fn dual(&self) -> $t {
+                self.xyz
+            }
+
+            #[inline]
+            pub fn layout() -> Layout {
+                Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+            }
+        }
+
+        impl $bt {
+            /// The wedge (aka exterior) product of this bivector and a vector.
+            ///
+            /// This results in a trivector, whose magnitude is the volume of the parallelepiped created
+            /// by extending the vector from the parallelogram represented by the bivector. Unlike the wedge
+            /// product of two vectors, this product is symmetric, i.e. `B ∧ v = v ∧ B`.
+            #[inline]
+            pub fn wedge(&self, v: $vt) -> $tn {
+                $tn::new(self.xy.mul_add(v.z, self.yz.mul_add(v.x, -(self.xz * v.y))))
+            }
+        }
+
+        impl EqualsEps for $tn {
+            fn eq_eps(self, other: Self) -> bool {
+                self.xyz.eq_eps(other.xyz)
+            }
+        }
+
+        impl Add for $tn {
+            type Output = Self;
+            #[inline]
+            fn add(mut self, rhs: $tn) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl AddAssign for $tn {
+            #[inline]
+            fn add_assign(&mut self, rhs: $tn) {
+                self.xyz += rhs.xyz;
+            }
+        }
+
+        impl Sub for $tn {
+            type Output = Self;
+            #[inline]
+            fn sub(mut self, rhs: $tn) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl SubAssign for $tn {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $tn) {
+                self.xyz -= rhs.xyz;
+            }
+        }
+
+        impl Mul<$tn> for $t {
+            type Output = $tn;
+            #[inline]
+            fn mul(self, mut rhs: $tn) -> $tn {
+                rhs *= self;
+                rhs
+            }
+        }
+
+        impl Mul<$t> for $tn {
+            type Output = Self;
+            #[inline]
+            fn mul(mut self, rhs: $t) -> Self {
+                self *= rhs;
+                self
+            }
+        }
+
+        impl MulAssign<$t> for $tn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                self.xyz *= rhs;
+            }
+        }
+
+        impl Div<$t> for $tn {
+            type Output = Self;
+            #[inline]
+            fn div(mut self, rhs: $t) -> Self {
+                self /= rhs;
+                self
+            }
+        }
+
+        impl DivAssign<$t> for $tn {
+            #[inline]
+            fn div_assign(&mut self, rhs: $t) {
+                self.xyz /= rhs;
+            }
+        }
+
+        impl Neg for $tn {
+            type Output = Self;
+            #[inline]
+            fn neg(mut self) -> Self {
+                self.xyz = -self.xyz;
+                self
+            }
+        }
+        )+
+    }
+}
+
+trivec3s!(
+    Trivec3 => (Vec3, Bivec3, f32),
+    WTrivec3 => (Wec3, WBivec3, f32x4),
+    WTrivec3x8 => (Wec3x8, WBivec3x8, f32x8),
+    DTrivec3 => (DVec3, DBivec3, f64),
+    DWTrivec3 => (DWec3, DWBivec3, f64x4)
+);
+
+impl PartialEq for Trivec3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.xyz == other.xyz
+    }
+}
+
+impl PartialEq for DTrivec3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.xyz == other.xyz
+    }
+}
+
+impl_f64_conversions!(Trivec3 => DTrivec3 { xyz });
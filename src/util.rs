@@ -1,4 +1,4 @@
-use wide::f32x4;
+use wide::{f32x4, f32x8, f64x4};
 
 pub trait EqualsEps {
     fn eq_eps(self, other: Self) -> bool;
@@ -6,8 +6,32 @@ pub trait EqualsEps {
 
 impl EqualsEps for f32x4 {
     fn eq_eps(self, other: Self) -> bool {
-        let r = (self - other).abs();
-        for eps in r.as_ref().iter() {
+        let r: [f32; 4] = (self - other).abs().into();
+        for eps in r.iter() {
+            if *eps > 0.01 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl EqualsEps for f32x8 {
+    fn eq_eps(self, other: Self) -> bool {
+        let r: [f32; 8] = (self - other).abs().into();
+        for eps in r.iter() {
+            if *eps > 0.01 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl EqualsEps for f64x4 {
+    fn eq_eps(self, other: Self) -> bool {
+        let r: [f64; 4] = (self - other).abs().into();
+        for eps in r.iter() {
             if *eps > 0.01 {
                 return false;
             }
@@ -22,6 +46,137 @@ impl EqualsEps for f32 {
     }
 }
 
+impl EqualsEps for f64 {
+    fn eq_eps(self, other: Self) -> bool {
+        (self - other).abs() <= 0.01
+    }
+}
+
+/// `atan2` for the wide types, which `wide` doesn't provide.
+pub(crate) trait WideAtan2 {
+    fn atan2(self, x: Self) -> Self;
+}
+
+macro_rules! impl_wide_atan2 {
+    ($($t:ident => $pi:expr),+) => {
+        $(impl WideAtan2 for $t {
+            #[inline]
+            fn atan2(self, x: Self) -> Self {
+                let zero = $t::from(0.0);
+                let one = $t::from(1.0);
+                let r = x.mul_add(x, self * self).sqrt();
+                let is_zero = r.cmp_eq(zero);
+                let r = is_zero.blend(one, r);
+                let cos = x / r;
+                let sin = self.abs() / r;
+
+                // acos loses precision near cos = +-1, and asin near sin = 1
+                let from_cos = cos.max(-one).min(one).acos();
+                let from_sin = sin.min(one).asin();
+                let from_sin = cos.cmp_lt(zero).blend($t::from($pi) - from_sin, from_sin);
+                let angle = cos.abs().cmp_lt($t::from(0.7)).blend(from_cos, from_sin);
+
+                let angle = self.cmp_lt(zero).blend(-angle, angle);
+                is_zero.blend(zero, angle)
+            }
+        })+
+    };
+}
+
+impl_wide_atan2!(
+    f32x4 => std::f32::consts::PI,
+    f32x8 => std::f32::consts::PI,
+    f64x4 => std::f64::consts::PI
+);
+
+/// Conversion between a single precision type and its double precision counterpart.
+///
+/// Implemented for the composite types by `impl_f64_conversions!`, which converts field by field.
+pub(crate) trait F64Conversion: Sized {
+    type F64;
+
+    fn widen(self) -> Self::F64;
+
+    fn narrow(wide: Self::F64) -> Self;
+}
+
+impl F64Conversion for f32 {
+    type F64 = f64;
+
+    #[inline]
+    fn widen(self) -> f64 {
+        f64::from(self)
+    }
+
+    #[inline]
+    fn narrow(wide: f64) -> f32 {
+        wide as f32
+    }
+}
+
+macro_rules! impl_array_f64_conversion {
+    ($($n:expr => [$($e:ident),+]),+) => {
+        $(impl<T: F64Conversion> F64Conversion for [T; $n] {
+            type F64 = [T::F64; $n];
+
+            #[inline]
+            fn widen(self) -> Self::F64 {
+                let [$($e),+] = self;
+                [$($e.widen()),+]
+            }
+
+            #[inline]
+            fn narrow(wide: Self::F64) -> Self {
+                let [$($e),+] = wide;
+                [$(T::narrow($e)),+]
+            }
+        })+
+    };
+}
+
+impl_array_f64_conversion!(
+    2 => [a, b],
+    3 => [a, b, c],
+    4 => [a, b, c, d]
+);
+
+/// Implements widening with `From` and narrowing with `into_f32` between a single precision type
+/// and its double precision counterpart, converting each of the listed fields.
+macro_rules! impl_f64_conversions {
+    ($($n:ident => $dn:ident { $($f:ident),+ }),+) => {
+        $(
+        impl crate::util::F64Conversion for $n {
+            type F64 = $dn;
+
+            #[inline]
+            fn widen(self) -> $dn {
+                $dn { $($f: crate::util::F64Conversion::widen(self.$f)),+ }
+            }
+
+            #[inline]
+            fn narrow(wide: $dn) -> Self {
+                $n { $($f: crate::util::F64Conversion::narrow(wide.$f)),+ }
+            }
+        }
+
+        impl From<$n> for $dn {
+            #[inline]
+            fn from(x: $n) -> Self {
+                crate::util::F64Conversion::widen(x)
+            }
+        }
+
+        impl $dn {
+            /// Narrow to single precision. This conversion is lossy.
+            #[inline]
+            pub fn into_f32(self) -> $n {
+                crate::util::F64Conversion::narrow(self)
+            }
+        }
+        )+
+    };
+}
+
 #[macro_export]
 macro_rules! derive_default_identity {
     ($t:ident) => {
@@ -5,7 +5,7 @@ use crate::util::*;
 use std::alloc::Layout;
 use std::ops::*;
 
-use wide::f32x4;
+use wide::{f32x4, f32x8, f64x4};
 
 macro_rules! vec2s {
     ($(($n:ident, $bn:ident, $rn:ident, $v3t:ident, $v4t:ident) => $t:ident),+) => {
@@ -496,17 +496,13 @@ macro_rules! vec2s {
     };
 }
 
-vec2s!((Vec2, Bivec2, Rotor2, Vec3, Vec4) => f32, (Wec2, WBivec2, WRotor2, Wec3, Wec4) => f32x4);
-
-impl From<[Vec2; 4]> for Wec2 {
-    #[inline]
-    fn from(vecs: [Vec2; 4]) -> Self {
-        Self {
-            x: f32x4::from([vecs[0].x, vecs[1].x, vecs[2].x, vecs[3].x]),
-            y: f32x4::from([vecs[0].y, vecs[1].y, vecs[2].y, vecs[3].y]),
-        }
-    }
-}
+vec2s!(
+    (Vec2, Bivec2, Rotor2, Vec3, Vec4) => f32,
+    (Wec2, WBivec2, WRotor2, Wec3, Wec4) => f32x4,
+    (Wec2x8, WBivec2x8, WRotor2x8, Wec3x8, Wec4x8) => f32x8,
+    (DVec2, DBivec2, DRotor2, DVec3, DVec4) => f64,
+    (DWec2, DWBivec2, DWRotor2, DWec3, DWec4) => f64x4
+);
 
 impl From<Vec3> for Vec2 {
     #[inline]
@@ -522,74 +518,140 @@ impl From<Wec3> for Wec2 {
     }
 }
 
-impl Vec2 {
+impl From<Wec3x8> for Wec2x8 {
     #[inline]
-    pub fn refract(&mut self, normal: Self, eta: f32) {
-        *self = self.refracted(normal, eta);
+    fn from(vec: Wec3x8) -> Self {
+        Self { x: vec.x, y: vec.y }
     }
+}
 
+impl From<DVec3> for DVec2 {
     #[inline]
-    pub fn refracted(&self, normal: Self, eta: f32) -> Self {
-        let n = normal;
-        let i = *self;
-        let ndi = n.dot(i);
-        let k = 1.0 - eta * eta * (1.0 - ndi * ndi);
-        if k < 0.0 {
-            Self::zero()
-        } else {
-            i * eta - (eta * ndi + k.sqrt()) * n
-        }
+    fn from(vec: DVec3) -> Self {
+        Self { x: vec.x, y: vec.y }
     }
 }
 
-impl Wec2 {
+impl From<DWec3> for DWec2 {
     #[inline]
-    pub fn new_splat(x: f32, y: f32) -> Self {
-        Self {
-            x: f32x4::from(x),
-            y: f32x4::from(y),
-        }
+    fn from(vec: DWec3) -> Self {
+        Self { x: vec.x, y: vec.y }
     }
+}
 
-    #[inline]
-    pub fn splat(vec: Vec2) -> Self {
-        Self::from([vec, vec, vec, vec])
-    }
+macro_rules! impl_scalar_refract {
+    ($($n:ident => $t:ident),+) => {
+        $(impl $n {
+            #[inline]
+            pub fn refract(&mut self, normal: Self, eta: $t) {
+                *self = self.refracted(normal, eta);
+            }
 
-    /// Merge two vectors together lanewise using `mask` as a mask.
-    ///
-    /// This is essentially a bitwise merge operation, such that any point where
-    /// there is a 1 bit in `mask`, the output will put the bit from `tru`, while
-    /// where there is a 0 bit in `mask`, the output will put the bit from `fals`
-    #[inline]
-    pub fn merge(mask: f32x4, tru: Self, fals: Self) -> Self {
-        Self {
-            x: mask.merge(tru.x, fals.x),
-            y: mask.merge(tru.y, fals.y),
-        }
-    }
+            #[inline]
+            pub fn refracted(&self, normal: Self, eta: $t) -> Self {
+                let n = normal;
+                let i = *self;
+                let ndi = n.dot(i);
+                let k = 1.0 - eta * eta * (1.0 - ndi * ndi);
+                if k < 0.0 {
+                    Self::zero()
+                } else {
+                    i * eta - (eta * ndi + k.sqrt()) * n
+                }
+            }
+        })+
+    };
+}
 
-    #[inline]
-    pub fn refract(&mut self, normal: Self, eta: f32x4) {
-        *self = self.refracted(normal, eta);
-    }
+impl_scalar_refract!(Vec2 => f32, DVec2 => f64);
 
-    #[inline]
-    pub fn refracted(&self, normal: Self, eta: f32x4) -> Self {
-        let n = normal;
-        let i = *self;
-        let one = f32x4::from(1.0);
-        let ndi = n.dot(i);
+macro_rules! wide_vec2s {
+    ($($n:ident => ($vt:ident, $tt:ident, $t:ident, $l:expr)),+) => {
+        $(impl $n {
+            #[inline]
+            pub fn new_splat(x: $t, y: $t) -> Self {
+                Self {
+                    x: $tt::from(x),
+                    y: $tt::from(y),
+                }
+            }
 
-        let k = one - eta * eta * (one - ndi * ndi);
-        let mask = k.cmp_lt(f32x4::from(0.0));
+            #[inline]
+            pub fn splat(vec: $vt) -> Self {
+                Self::from([vec; $l])
+            }
 
-        let out = i * eta - (eta * ndi + k.sqrt()) * n;
+            /// Merge two vectors together lanewise using `mask` as a mask.
+            ///
+            /// Each lane of `mask` must be either all ones or all zeros, such as the masks returned
+            /// by the `cmp_*` methods of the lane type. Where a lane of `mask` is set, the output
+            /// takes the lane from `tru`, otherwise it takes the lane from `fals`.
+            #[inline]
+            pub fn merge(mask: $tt, tru: Self, fals: Self) -> Self {
+                Self {
+                    x: mask.blend(tru.x, fals.x),
+                    y: mask.blend(tru.y, fals.y),
+                }
+            }
 
-        Self::merge(mask, Self::zero(), out)
-    }
+            #[inline]
+            pub fn refract(&mut self, normal: Self, eta: $tt) {
+                *self = self.refracted(normal, eta);
+            }
+
+            #[inline]
+            pub fn refracted(&self, normal: Self, eta: $tt) -> Self {
+                let n = normal;
+                let i = *self;
+                let one = $tt::from(1.0);
+                let ndi = n.dot(i);
+
+                let k = one - eta * eta * (one - ndi * ndi);
+                let mask = k.cmp_lt($tt::from(0.0));
+
+                let out = i * eta - (eta * ndi + k.sqrt()) * n;
+
+                Self::merge(mask, Self::zero(), out)
+            }
+        }
+
+        impl From<$n> for [$vt; $l] {
+            #[inline]
+            fn from(v: $n) -> Self {
+                let xs: [$t; $l] = v.x.into();
+                let ys: [$t; $l] = v.y.into();
+                let mut vecs = [$vt::zero(); $l];
+                for (i, vec) in vecs.iter_mut().enumerate() {
+                    *vec = $vt::new(xs[i], ys[i]);
+                }
+                vecs
+            }
+        }
+
+        impl From<[$vt; $l]> for $n {
+            #[inline]
+            fn from(vecs: [$vt; $l]) -> Self {
+                let mut xs = [$t::from(0.0); $l];
+                let mut ys = [$t::from(0.0); $l];
+                for (i, vec) in vecs.iter().enumerate() {
+                    xs[i] = vec.x;
+                    ys[i] = vec.y;
+                }
+                Self {
+                    x: $tt::from(xs),
+                    y: $tt::from(ys),
+                }
+            }
+        })+
+    };
 }
 
+wide_vec2s!(
+    Wec2 => (Vec2, f32x4, f32, 4),
+    Wec2x8 => (Vec2, f32x8, f32, 8),
+    DWec2 => (DVec2, f64x4, f64, 4)
+);
+
 impl PartialEq for Vec2 {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y
@@ -718,6 +780,13 @@ macro_rules! vec3s {
                 self
             }
 
+            /// The (Hodge) dual of this vector, i.e. the bivector representing the plane
+            /// normal to it and with the same magnitude. This is the inverse of `Bivec3::dual`.
+            #[inline]
+            pub fn dual(&self) -> $bn {
+                $bn::new(self.z, -self.y, self.x)
+            }
+
             #[inline]
             pub fn cross(&self, other: $n) -> Self {
                 $n::new(
@@ -1140,7 +1209,13 @@ macro_rules! vec3s {
     }
 }
 
-vec3s!((Vec2, Vec3, Bivec3, Rotor3, Vec4) => f32, (Wec2, Wec3, WBivec3, WRotor3, Wec4) => f32x4);
+vec3s!(
+    (Vec2, Vec3, Bivec3, Rotor3, Vec4) => f32,
+    (Wec2, Wec3, WBivec3, WRotor3, Wec4) => f32x4,
+    (Wec2x8, Wec3x8, WBivec3x8, WRotor3x8, Wec4x8) => f32x8,
+    (DVec2, DVec3, DBivec3, DRotor3, DVec4) => f64,
+    (DWec2, DWec3, DWBivec3, DWRotor3, DWec4) => f64x4
+);
 
 impl From<Vec2> for Vec3 {
     #[inline]
@@ -1186,102 +1261,167 @@ impl From<Wec4> for Wec3 {
     }
 }
 
-impl Vec3 {
+impl From<Wec2x8> for Wec3x8 {
     #[inline]
-    pub fn refract(&mut self, normal: Self, eta: f32) {
-        *self = self.refracted(normal, eta);
+    fn from(vec: Wec2x8) -> Self {
+        Self {
+            x: vec.x,
+            y: vec.y,
+            z: f32x8::from(0.0),
+        }
     }
+}
 
+impl From<Wec4x8> for Wec3x8 {
     #[inline]
-    pub fn refracted(&self, normal: Self, eta: f32) -> Self {
-        let n = normal;
-        let i = *self;
-        let ndi = n.dot(i);
-        let k = 1.0 - eta * eta * (1.0 - ndi * ndi);
-        if k < 0.0 {
-            Self::zero()
-        } else {
-            i * eta - (eta * ndi + k.sqrt()) * n
+    fn from(vec: Wec4x8) -> Self {
+        Self {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
         }
     }
 }
 
-impl Wec3 {
+impl From<DVec2> for DVec3 {
     #[inline]
-    pub fn new_splat(x: f32, y: f32, z: f32) -> Self {
+    fn from(vec: DVec2) -> Self {
         Self {
-            x: f32x4::from(x),
-            y: f32x4::from(y),
-            z: f32x4::from(z),
+            x: vec.x,
+            y: vec.y,
+            z: 0.0,
         }
     }
+}
 
+impl From<DVec4> for DVec3 {
     #[inline]
-    pub fn splat(vec: Vec3) -> Self {
-        Self::from([vec, vec, vec, vec])
+    fn from(vec: DVec4) -> Self {
+        Self {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+        }
     }
+}
 
-    /// Merge two vectors together lanewise using `mask` as a mask.
-    ///
-    /// This is essentially a bitwise merge operation, such that any point where
-    /// there is a 1 bit in `mask`, the output will put the bit from `tru`, while
-    /// where there is a 0 bit in `mask`, the output will put the bit from `fals`
+impl From<DWec2> for DWec3 {
     #[inline]
-    pub fn merge(mask: f32x4, tru: Self, fals: Self) -> Self {
+    fn from(vec: DWec2) -> Self {
         Self {
-            x: mask.merge(tru.x, fals.x),
-            y: mask.merge(tru.y, fals.y),
-            z: mask.merge(tru.z, fals.z),
+            x: vec.x,
+            y: vec.y,
+            z: f64x4::from(0.0),
         }
     }
+}
 
+impl From<DWec4> for DWec3 {
     #[inline]
-    pub fn refract(&mut self, normal: Self, eta: f32x4) {
-        *self = self.refracted(normal, eta);
+    fn from(vec: DWec4) -> Self {
+        Self {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+        }
     }
+}
 
-    #[inline]
-    pub fn refracted(&self, normal: Self, eta: f32x4) -> Self {
-        let n = normal;
-        let i = *self;
-        let one = f32x4::from(1.0);
-        let ndi = n.dot(i);
+impl_scalar_refract!(Vec3 => f32, DVec3 => f64);
 
-        let k = one - eta * eta * (one - ndi * ndi);
-        let mask = k.cmp_lt(f32x4::from(0.0));
+macro_rules! wide_vec3s {
+    ($($n:ident => ($vt:ident, $tt:ident, $t:ident, $l:expr)),+) => {
+        $(impl $n {
+            #[inline]
+            pub fn new_splat(x: $t, y: $t, z: $t) -> Self {
+                Self {
+                    x: $tt::from(x),
+                    y: $tt::from(y),
+                    z: $tt::from(z),
+                }
+            }
 
-        let out = i.mul_add(Wec3::broadcast(eta), -(eta * ndi + k.sqrt()) * n);
+            #[inline]
+            pub fn splat(vec: $vt) -> Self {
+                Self::from([vec; $l])
+            }
 
-        Self::merge(mask, Self::zero(), out)
-    }
-}
+            /// Merge two vectors together lanewise using `mask` as a mask.
+            ///
+            /// Each lane of `mask` must be either all ones or all zeros, such as the masks returned
+            /// by the `cmp_*` methods of the lane type. Where a lane of `mask` is set, the output
+            /// takes the lane from `tru`, otherwise it takes the lane from `fals`.
+            #[inline]
+            pub fn merge(mask: $tt, tru: Self, fals: Self) -> Self {
+                Self {
+                    x: mask.blend(tru.x, fals.x),
+                    y: mask.blend(tru.y, fals.y),
+                    z: mask.blend(tru.z, fals.z),
+                }
+            }
 
-impl Into<[Vec3; 4]> for Wec3 {
-    #[inline]
-    fn into(self) -> [Vec3; 4] {
-        let xs = self.x.as_ref();
-        let ys = self.y.as_ref();
-        let zs = self.z.as_ref();
-        [
-            Vec3::new(xs[0], ys[0], zs[0]),
-            Vec3::new(xs[1], ys[1], zs[1]),
-            Vec3::new(xs[2], ys[2], zs[2]),
-            Vec3::new(xs[3], ys[3], zs[3]),
-        ]
-    }
-}
+            #[inline]
+            pub fn refract(&mut self, normal: Self, eta: $tt) {
+                *self = self.refracted(normal, eta);
+            }
 
-impl From<[Vec3; 4]> for Wec3 {
-    #[inline]
-    fn from(vecs: [Vec3; 4]) -> Self {
-        Self {
-            x: f32x4::from([vecs[0].x, vecs[1].x, vecs[2].x, vecs[3].x]),
-            y: f32x4::from([vecs[0].y, vecs[1].y, vecs[2].y, vecs[3].y]),
-            z: f32x4::from([vecs[0].z, vecs[1].z, vecs[2].z, vecs[3].z]),
+            #[inline]
+            pub fn refracted(&self, normal: Self, eta: $tt) -> Self {
+                let n = normal;
+                let i = *self;
+                let one = $tt::from(1.0);
+                let ndi = n.dot(i);
+
+                let k = one - eta * eta * (one - ndi * ndi);
+                let mask = k.cmp_lt($tt::from(0.0));
+
+                let out = i.mul_add(Self::broadcast(eta), -(eta * ndi + k.sqrt()) * n);
+
+                Self::merge(mask, Self::zero(), out)
+            }
         }
-    }
+
+        impl From<$n> for [$vt; $l] {
+            #[inline]
+            fn from(v: $n) -> Self {
+                let xs: [$t; $l] = v.x.into();
+                let ys: [$t; $l] = v.y.into();
+                let zs: [$t; $l] = v.z.into();
+                let mut vecs = [$vt::zero(); $l];
+                for (i, vec) in vecs.iter_mut().enumerate() {
+                    *vec = $vt::new(xs[i], ys[i], zs[i]);
+                }
+                vecs
+            }
+        }
+
+        impl From<[$vt; $l]> for $n {
+            #[inline]
+            fn from(vecs: [$vt; $l]) -> Self {
+                let mut xs = [$t::from(0.0); $l];
+                let mut ys = [$t::from(0.0); $l];
+                let mut zs = [$t::from(0.0); $l];
+                for (i, vec) in vecs.iter().enumerate() {
+                    xs[i] = vec.x;
+                    ys[i] = vec.y;
+                    zs[i] = vec.z;
+                }
+                Self {
+                    x: $tt::from(xs),
+                    y: $tt::from(ys),
+                    z: $tt::from(zs),
+                }
+            }
+        })+
+    };
 }
 
+wide_vec3s!(
+    Wec3 => (Vec3, f32x4, f32, 4),
+    Wec3x8 => (Vec3, f32x8, f32, 8),
+    DWec3 => (DVec3, f64x4, f64, 4)
+);
+
 impl PartialEq for Vec3 {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y && self.z == other.z
@@ -1293,7 +1433,7 @@ impl PartialEq for Vec3 {
 }
 
 macro_rules! vec4s {
-    ($($n:ident, $v2t:ident, $v3t:ident => $t:ident),+) => {
+    ($($n:ident, $v2t:ident, $v3t:ident, $bn:ident, $rn:ident => $t:ident),+) => {
         /// A set of four coordinates which may be interpreted as a point or vector in 4d space,
         /// or as a homogeneous 3d vector or point.
         ///
@@ -1345,6 +1485,49 @@ macro_rules! vec4s {
                 self.x.mul_add(other.x, self.y.mul_add(other.y, self.z.mul_add(other.z, self.w * other.w)))
             }
 
+            /// The wedge (aka exterior) product of two vectors.
+            ///
+            /// This operation results in a bivector, which represents
+            /// the plane parallel to the two vectors, and which has a
+            /// 'oriented area' equal to the parallelogram created by extending
+            /// the two vectors, oriented such that the positive direction is the
+            /// one which would move `self` closer to `other`.
+            #[inline]
+            pub fn wedge(&self, other: $n) -> $bn {
+                $bn::new(
+                    self.x.mul_add(other.y, -(self.y * other.x)),
+                    self.x.mul_add(other.z, -(self.z * other.x)),
+                    self.x.mul_add(other.w, -(self.w * other.x)),
+                    self.y.mul_add(other.z, -(self.z * other.y)),
+                    self.y.mul_add(other.w, -(self.w * other.y)),
+                    self.z.mul_add(other.w, -(self.w * other.z)),
+                )
+            }
+
+            /// The geometric product of this and another vector, which
+            /// is defined as the sum of the dot product and the wedge product.
+            ///
+            /// This operation results in a 'rotor', named as such as it may define
+            /// a rotation. The rotor which results from the geometric product
+            /// will rotate in the plane parallel to the two vectors, by twice the angle between
+            /// them and in the opposite direction (i.e. it will rotate in the direction that would
+            /// bring `other` towards `self`, and rotate in that direction by twice the angle between them).
+            #[inline]
+            pub fn geom(&self, other: $n) -> $rn {
+                $rn::new(self.dot(other), self.wedge(other), $t::from(0.0))
+            }
+
+            #[inline]
+            pub fn rotate_by(&mut self, rotor: $rn) {
+                rotor.rotate_vec(self);
+            }
+
+            #[inline]
+            pub fn rotated_by(mut self, rotor: $rn) -> Self {
+                rotor.rotate_vec(&mut self);
+                self
+            }
+
             #[inline]
             pub fn reflect(&mut self, normal: $n) {
                 *self -= $t::from(2.0) * self.dot(normal) * normal;
@@ -1773,7 +1956,13 @@ macro_rules! vec4s {
     }
 }
 
-vec4s!(Vec4, Vec2, Vec3 => f32, Wec4, Wec2, Wec3 => f32x4);
+vec4s!(
+    Vec4, Vec2, Vec3, Bivec4, Rotor4 => f32,
+    Wec4, Wec2, Wec3, WBivec4, WRotor4 => f32x4,
+    Wec4x8, Wec2x8, Wec3x8, WBivec4x8, WRotor4x8 => f32x8,
+    DVec4, DVec2, DVec3, DBivec4, DRotor4 => f64,
+    DWec4, DWec2, DWec3, DWBivec4, DWRotor4 => f64x4
+);
 
 impl From<Vec3> for Vec4 {
     #[inline]
@@ -1799,86 +1988,123 @@ impl From<Wec3> for Wec4 {
     }
 }
 
-impl Vec4 {
-    #[inline]
-    pub fn refract(&mut self, normal: Self, eta: f32) {
-        *self = self.refracted(normal, eta);
-    }
-
+impl From<Wec3x8> for Wec4x8 {
     #[inline]
-    pub fn refracted(&self, normal: Self, eta: f32) -> Self {
-        let n = normal;
-        let i = *self;
-        let ndi = n.dot(i);
-        let k = 1.0 - eta * eta * (1.0 - ndi * ndi);
-        if k < 0.0 {
-            Self::zero()
-        } else {
-            i * eta - (eta * ndi + k.sqrt()) * n
+    fn from(vec: Wec3x8) -> Self {
+        Self {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+            w: f32x8::from(0.0),
         }
     }
 }
 
-impl Wec4 {
+impl From<DVec3> for DVec4 {
     #[inline]
-    pub fn new_splat(x: f32, y: f32, z: f32, w: f32) -> Self {
+    fn from(vec: DVec3) -> Self {
         Self {
-            x: f32x4::from(x),
-            y: f32x4::from(y),
-            z: f32x4::from(z),
-            w: f32x4::from(w),
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+            w: 0.0,
         }
     }
+}
 
+impl From<DWec3> for DWec4 {
     #[inline]
-    pub fn splat(vec: Vec4) -> Self {
-        Self::from([vec, vec, vec, vec])
-    }
-
-    /// Merge two vectors together lanewise using `mask` as a mask.
-    ///
-    /// This is essentially a bitwise merge operation, such that any point where
-    /// there is a 1 bit in `mask`, the output will put the bit from `tru`, while
-    /// where there is a 0 bit in `mask`, the output will put the bit from `fals`
-    #[inline]
-    pub fn merge(mask: f32x4, tru: Self, fals: Self) -> Self {
+    fn from(vec: DWec3) -> Self {
         Self {
-            x: mask.merge(tru.x, fals.x),
-            y: mask.merge(tru.y, fals.y),
-            z: mask.merge(tru.z, fals.z),
-            w: mask.merge(tru.w, fals.w),
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+            w: f64x4::from(0.0),
         }
     }
 }
 
-impl Into<[Vec4; 4]> for Wec4 {
-    #[inline]
-    fn into(self) -> [Vec4; 4] {
-        let xs = self.x.as_ref();
-        let ys = self.y.as_ref();
-        let zs = self.z.as_ref();
-        let ws = self.w.as_ref();
-        [
-            Vec4::new(xs[0], ys[0], zs[0], ws[0]),
-            Vec4::new(xs[1], ys[1], zs[1], ws[1]),
-            Vec4::new(xs[2], ys[2], zs[2], ws[2]),
-            Vec4::new(xs[3], ys[3], zs[3], ws[3]),
-        ]
-    }
-}
+impl_scalar_refract!(Vec4 => f32, DVec4 => f64);
 
-impl From<[Vec4; 4]> for Wec4 {
-    #[inline]
-    fn from(vecs: [Vec4; 4]) -> Self {
-        Self {
-            x: f32x4::from([vecs[0].x, vecs[1].x, vecs[2].x, vecs[3].x]),
-            y: f32x4::from([vecs[0].y, vecs[1].y, vecs[2].y, vecs[3].y]),
-            z: f32x4::from([vecs[0].z, vecs[1].z, vecs[2].z, vecs[3].z]),
-            w: f32x4::from([vecs[0].w, vecs[1].w, vecs[2].w, vecs[3].w]),
+macro_rules! wide_vec4s {
+    ($($n:ident => ($vt:ident, $tt:ident, $t:ident, $l:expr)),+) => {
+        $(impl $n {
+            #[inline]
+            pub fn new_splat(x: $t, y: $t, z: $t, w: $t) -> Self {
+                Self {
+                    x: $tt::from(x),
+                    y: $tt::from(y),
+                    z: $tt::from(z),
+                    w: $tt::from(w),
+                }
+            }
+
+            #[inline]
+            pub fn splat(vec: $vt) -> Self {
+                Self::from([vec; $l])
+            }
+
+            /// Merge two vectors together lanewise using `mask` as a mask.
+            ///
+            /// Each lane of `mask` must be either all ones or all zeros, such as the masks returned
+            /// by the `cmp_*` methods of the lane type. Where a lane of `mask` is set, the output
+            /// takes the lane from `tru`, otherwise it takes the lane from `fals`.
+            #[inline]
+            pub fn merge(mask: $tt, tru: Self, fals: Self) -> Self {
+                Self {
+                    x: mask.blend(tru.x, fals.x),
+                    y: mask.blend(tru.y, fals.y),
+                    z: mask.blend(tru.z, fals.z),
+                    w: mask.blend(tru.w, fals.w),
+                }
+            }
         }
-    }
+
+        impl From<$n> for [$vt; $l] {
+            #[inline]
+            fn from(v: $n) -> Self {
+                let xs: [$t; $l] = v.x.into();
+                let ys: [$t; $l] = v.y.into();
+                let zs: [$t; $l] = v.z.into();
+                let ws: [$t; $l] = v.w.into();
+                let mut vecs = [$vt::zero(); $l];
+                for (i, vec) in vecs.iter_mut().enumerate() {
+                    *vec = $vt::new(xs[i], ys[i], zs[i], ws[i]);
+                }
+                vecs
+            }
+        }
+
+        impl From<[$vt; $l]> for $n {
+            #[inline]
+            fn from(vecs: [$vt; $l]) -> Self {
+                let mut xs = [$t::from(0.0); $l];
+                let mut ys = [$t::from(0.0); $l];
+                let mut zs = [$t::from(0.0); $l];
+                let mut ws = [$t::from(0.0); $l];
+                for (i, vec) in vecs.iter().enumerate() {
+                    xs[i] = vec.x;
+                    ys[i] = vec.y;
+                    zs[i] = vec.z;
+                    ws[i] = vec.w;
+                }
+                Self {
+                    x: $tt::from(xs),
+                    y: $tt::from(ys),
+                    z: $tt::from(zs),
+                    w: $tt::from(ws),
+                }
+            }
+        })+
+    };
 }
 
+wide_vec4s!(
+    Wec4 => (Vec4, f32x4, f32, 4),
+    Wec4x8 => (Vec4, f32x8, f32, 8),
+    DWec4 => (DVec4, f64x4, f64, 4)
+);
+
 impl PartialEq for Vec4 {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
@@ -1888,3 +2114,27 @@ impl PartialEq for Vec4 {
         self.x != other.x || self.y != other.y || self.z != other.z || self.w != other.w
     }
 }
+
+impl PartialEq for DVec2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl PartialEq for DVec3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+impl PartialEq for DVec4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
+
+impl_f64_conversions!(
+    Vec2 => DVec2 { x, y },
+    Vec3 => DVec3 { x, y, z },
+    Vec4 => DVec4 { x, y, z, w }
+);